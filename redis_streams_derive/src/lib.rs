@@ -0,0 +1,169 @@
+//! Derive macros for the `FromStreamEntry` and `ToStreamEntry` traits
+//! of `redis_streams`.
+//!
+//! Don't depend on this crate directly, enable the `derive` feature
+//! of `redis_streams` instead.
+//!
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Fields, Ident, Lit, Meta, NestedMeta};
+
+/// How a struct field maps to an entry field.
+struct EntryField {
+    ident: Ident,
+    name: String,
+    default: bool,
+    optional: bool,
+}
+
+impl EntryField {
+    fn parse(field: &syn::Field) -> syn::Result<Self> {
+        let ident = field.ident.clone().expect("named field");
+        let mut entry_field = EntryField {
+            name: ident.to_string(),
+            ident,
+            default: false,
+            optional: false,
+        };
+
+        for attr in field.attrs.iter().filter(|a| a.path.is_ident("stream")) {
+            let list = match attr.parse_meta()? {
+                Meta::List(list) => list,
+                meta => return Err(syn::Error::new_spanned(meta, "expected #[stream(...)]")),
+            };
+            for nested in list.nested {
+                match nested {
+                    NestedMeta::Meta(Meta::NameValue(ref nv)) if nv.path.is_ident("rename") => {
+                        match nv.lit {
+                            Lit::Str(ref s) => entry_field.name = s.value(),
+                            ref lit => {
+                                return Err(syn::Error::new_spanned(lit, "expected a string"))
+                            }
+                        }
+                    }
+                    NestedMeta::Meta(Meta::Path(ref path)) if path.is_ident("default") => {
+                        entry_field.default = true;
+                    }
+                    NestedMeta::Meta(Meta::Path(ref path)) if path.is_ident("optional") => {
+                        entry_field.optional = true;
+                    }
+                    other => {
+                        return Err(syn::Error::new_spanned(
+                            other,
+                            "expected `rename = \"...\"`, `default` or `optional`",
+                        ))
+                    }
+                }
+            }
+        }
+
+        if entry_field.default && entry_field.optional {
+            return Err(syn::Error::new_spanned(
+                &field.ident,
+                "`default` and `optional` can't be used together",
+            ));
+        }
+        Ok(entry_field)
+    }
+}
+
+fn entry_fields(input: &DeriveInput) -> syn::Result<Vec<EntryField>> {
+    match input.data {
+        Data::Struct(ref data) => match data.fields {
+            Fields::Named(ref fields) => fields.named.iter().map(EntryField::parse).collect(),
+            _ => Err(syn::Error::new_spanned(
+                &input.ident,
+                "stream entries can only be derived for structs with named fields",
+            )),
+        },
+        _ => Err(syn::Error::new_spanned(
+            &input.ident,
+            "stream entries can only be derived for structs",
+        )),
+    }
+}
+
+/// Derive `redis_streams::FromStreamEntry` for a struct with named fields.
+#[proc_macro_derive(FromStreamEntry, attributes(stream))]
+pub fn derive_from_stream_entry(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    from_stream_entry(&input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+/// Derive `redis_streams::ToStreamEntry` for a struct with named fields.
+#[proc_macro_derive(ToStreamEntry, attributes(stream))]
+pub fn derive_to_stream_entry(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    to_stream_entry(&input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+fn from_stream_entry(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = entry_fields(input)?;
+    let reads = fields.iter().map(|f| {
+        let ident = &f.ident;
+        let name = &f.name;
+        let read = if f.optional {
+            quote!(optional)
+        } else if f.default {
+            quote!(or_default)
+        } else {
+            quote!(required)
+        };
+        quote! {
+            #ident: ::redis_streams::__derive::#read(entry, #name)?
+        }
+    });
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::redis_streams::FromStreamEntry for #ident #ty_generics #where_clause {
+            fn from_stream_entry<F: AsRef<[u8]>>(
+                entry: &::redis_streams::StreamId<F>,
+            ) -> ::redis_streams::RedisResult<Self> {
+                Ok(#ident {
+                    #(#reads,)*
+                })
+            }
+        }
+    })
+}
+
+fn to_stream_entry(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = entry_fields(input)?;
+    let capacity = fields.len();
+    let writes = fields.iter().map(|f| {
+        let ident = &f.ident;
+        let name = &f.name;
+        if f.optional {
+            quote! {
+                if let Some(ref value) = self.#ident {
+                    ::redis_streams::__derive::push(&mut fields, #name, value)?;
+                }
+            }
+        } else {
+            quote! {
+                ::redis_streams::__derive::push(&mut fields, #name, &self.#ident)?;
+            }
+        }
+    });
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::redis_streams::ToStreamEntry for #ident #ty_generics #where_clause {
+            fn to_stream_entry(&self) -> ::redis_streams::RedisResult<Vec<(String, Vec<u8>)>> {
+                let mut fields = Vec::with_capacity(#capacity);
+                #(#writes)*
+                Ok(fields)
+            }
+        }
+    })
+}
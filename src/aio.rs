@@ -0,0 +1,1204 @@
+use crate::entry::FromStreamEntry;
+use crate::idempotent::{dedup_key, IdempotentProducer};
+use crate::pipeline::StreamPipelineExt;
+use crate::scripts::{XACK_AND_ADD, XADD_IDEMPOTENT};
+use crate::tail::StreamTail;
+use crate::types::{
+    AddId, RangeBound, ReadFrom, StreamAddOptions, StreamAutoClaimOptions, StreamAutoClaimReply,
+    StreamClaimOptions, StreamClaimReply, StreamEntryId, StreamGroupCreateOptions,
+    StreamInfoConsumersReply, StreamInfoGroupsReply, StreamInfoStreamFullReply,
+    StreamInfoStreamReply, StreamPendingCountReply, StreamPendingOptions, StreamPendingReply,
+    StreamRangeReply, StreamReadBytesReply, StreamReadMode, StreamReadOptions, StreamReadReply,
+    StreamSetIdOptions, StreamTrimStrategy, StreamTypedReply,
+};
+
+use futures::Future;
+use redis::aio::ConnectionLike;
+use redis::{cmd, FromRedisValue, RedisFuture, ToRedisArgs};
+
+/// Implementation of all redis stream commands for async connections.
+///
+/// Each method mirrors the blocking version in `StreamCommands`, consuming
+/// the connection and returning a future which resolves to the connection
+/// along with the same typed reply.
+///
+/// A few `StreamCommands` methods have no async version:
+///
+/// - `xread_iter` is [`xread_stream`] here, which returns a `Stream`.
+/// - `xrange_iter`, `xrevrange_iter` and `xautoclaim_iter` have no
+///   `Stream` adapter. Page with `xrange_count`/`xrevrange_count`, or with
+///   `xautoclaim_options` starting at the `next_cursor` of the last reply.
+/// - `xgroup_create_if_missing` has to swallow the `BUSYGROUP` error,
+///   which ends the async connection. Use `xgroup_create_options`.
+///
+/// ```no_run
+/// use futures::Future;
+/// use redis_streams::{client_open,AsyncStreamCommands,ReadFrom,StreamEntryId,StreamReadReply};
+/// let client = client_open("redis://127.0.0.1/0").unwrap();
+///
+/// let future = client
+///     .get_async_connection()
+///     .and_then(|con| con.xread(&["k1"], &[ReadFrom::After(StreamEntryId::MIN)]))
+///     .map(|(_con, reply): (_, StreamReadReply)| reply.keys.len());
+/// ```
+///
+/// [`xread_stream`]: #method.xread_stream
+///
+pub trait AsyncStreamCommands: ConnectionLike + Send + Sized + 'static {
+    // XACK <key> <group> <id> <id> ... <id>
+
+    /// Async version of [`xack`].
+    ///
+    /// [`xack`]: ./trait.StreamCommands.html#method.xack
+    ///
+    #[inline]
+    fn xack<
+        K: ToRedisArgs,
+        G: ToRedisArgs,
+        ID: ToRedisArgs,
+        RV: FromRedisValue + Send + 'static,
+    >(
+        self,
+        key: K,
+        group: G,
+        ids: &[ID],
+    ) -> RedisFuture<(Self, RV)> {
+        cmd("XACK").arg(key).arg(group).arg(ids).query_async(self)
+    }
+
+    // XACK <src_key> <group> <id> + XADD <dst_key> * [field value] ... (Lua script)
+
+    /// Async version of [`xack_and_add`].
+    ///
+    /// The script is sent with `EVAL` on every call, since a `NOSCRIPT`
+    /// error would end the connection.
+    ///
+    /// [`xack_and_add`]: ./trait.StreamCommands.html#method.xack_and_add
+    ///
+    #[inline]
+    fn xack_and_add<
+        S: ToRedisArgs,
+        G: ToRedisArgs,
+        D: ToRedisArgs,
+        F: ToRedisArgs,
+        V: ToRedisArgs,
+    >(
+        self,
+        src_key: S,
+        group: G,
+        id: StreamEntryId,
+        dst_key: D,
+        fields: &[(F, V)],
+    ) -> RedisFuture<(Self, Option<StreamEntryId>)> {
+        cmd("EVAL")
+            .arg(XACK_AND_ADD)
+            .arg(2)
+            .arg(src_key)
+            .arg(dst_key)
+            .arg(group)
+            .arg(id)
+            .arg(fields)
+            .query_async(self)
+    }
+
+    // MULTI
+    // XACK <src_key> <group> <id>
+    // XADD <dst_key> * [field value] ...
+    // EXEC
+
+    /// Async version of [`xack_and_add_multi`].
+    ///
+    /// [`xack_and_add_multi`]: ./trait.StreamCommands.html#method.xack_and_add_multi
+    ///
+    #[inline]
+    fn xack_and_add_multi<
+        S: ToRedisArgs,
+        G: ToRedisArgs,
+        D: ToRedisArgs,
+        F: ToRedisArgs,
+        V: ToRedisArgs,
+    >(
+        self,
+        src_key: S,
+        group: G,
+        id: StreamEntryId,
+        dst_key: D,
+        fields: &[(F, V)],
+    ) -> RedisFuture<(Self, StreamEntryId)> {
+        let mut pipe = redis::pipe();
+        pipe.atomic()
+            .xack(src_key, group, &[id])
+            .ignore()
+            .xadd(dst_key, AddId::Auto, fields);
+        Box::new(
+            pipe.query_async(self)
+                .map(|(con, (new_id,)): (Self, (StreamEntryId,))| (con, new_id)),
+        )
+    }
+
+    // XADD key <ID or *> [field value] [field value] ...
+
+    /// Async version of [`xadd`].
+    ///
+    /// [`xadd`]: ./trait.StreamCommands.html#method.xadd
+    ///
+    #[inline]
+    fn xadd<
+        K: ToRedisArgs,
+        ID: Into<AddId>,
+        F: ToRedisArgs,
+        V: ToRedisArgs,
+        RV: FromRedisValue + Send + 'static,
+    >(
+        self,
+        key: K,
+        id: ID,
+        items: &[(F, V)],
+    ) -> RedisFuture<(Self, RV)> {
+        cmd("XADD")
+            .arg(key)
+            .arg(id.into())
+            .arg(items)
+            .query_async(self)
+    }
+
+    // XADD key <ID or *> [rust BTreeMap] ...
+
+    /// Async version of [`xadd_map`].
+    ///
+    /// [`xadd_map`]: ./trait.StreamCommands.html#method.xadd_map
+    ///
+    #[inline]
+    fn xadd_map<
+        K: ToRedisArgs,
+        ID: Into<AddId>,
+        BTM: ToRedisArgs,
+        RV: FromRedisValue + Send + 'static,
+    >(
+        self,
+        key: K,
+        id: ID,
+        map: BTM,
+    ) -> RedisFuture<(Self, RV)> {
+        cmd("XADD")
+            .arg(key)
+            .arg(id.into())
+            .arg(map)
+            .query_async(self)
+    }
+
+    // XADD key [MAXLEN|MINID [~|=] <threshold> [LIMIT <count>]] <ID or *> [field value] ...
+
+    /// Async version of [`xadd_maxlen`].
+    ///
+    /// [`xadd_maxlen`]: ./trait.StreamCommands.html#method.xadd_maxlen
+    ///
+    #[inline]
+    fn xadd_maxlen<
+        K: ToRedisArgs,
+        T: Into<StreamTrimStrategy>,
+        ID: Into<AddId>,
+        F: ToRedisArgs,
+        V: ToRedisArgs,
+        RV: FromRedisValue + Send + 'static,
+    >(
+        self,
+        key: K,
+        trim: T,
+        id: ID,
+        items: &[(F, V)],
+    ) -> RedisFuture<(Self, RV)> {
+        cmd("XADD")
+            .arg(key)
+            .arg(trim.into())
+            .arg(id.into())
+            .arg(items)
+            .query_async(self)
+    }
+
+    // XADD key [MAXLEN|MINID [~|=] <threshold> [LIMIT <count>]] <ID or *> [rust BTreeMap] ...
+
+    /// Async version of [`xadd_maxlen_map`].
+    ///
+    /// [`xadd_maxlen_map`]: ./trait.StreamCommands.html#method.xadd_maxlen_map
+    ///
+    #[inline]
+    fn xadd_maxlen_map<
+        K: ToRedisArgs,
+        T: Into<StreamTrimStrategy>,
+        ID: Into<AddId>,
+        BTM: ToRedisArgs,
+        RV: FromRedisValue + Send + 'static,
+    >(
+        self,
+        key: K,
+        trim: T,
+        id: ID,
+        map: BTM,
+    ) -> RedisFuture<(Self, RV)> {
+        cmd("XADD")
+            .arg(key)
+            .arg(trim.into())
+            .arg(id.into())
+            .arg(map)
+            .query_async(self)
+    }
+
+    // XADD key [NOMKSTREAM] [MAXLEN|MINID [~|=] <threshold> [LIMIT <count>]]
+    //     <ID or *> [field value] [field value] ...
+
+    /// Async version of [`xadd_options`].
+    ///
+    /// [`xadd_options`]: ./trait.StreamCommands.html#method.xadd_options
+    ///
+    #[inline]
+    fn xadd_options<K: ToRedisArgs, F: ToRedisArgs, V: ToRedisArgs>(
+        self,
+        key: K,
+        items: &[(F, V)],
+        options: StreamAddOptions,
+    ) -> RedisFuture<(Self, Option<StreamEntryId>)> {
+        cmd("XADD")
+            .arg(key)
+            .arg(options)
+            .arg(items)
+            .query_async(self)
+    }
+
+    // XADD key * [field value] ... producer-id <producer_id> producer-seq <seq> (Lua script)
+
+    /// Async version of [`xadd_idempotent`].
+    ///
+    /// The script is sent with `EVAL` on every call, since a `NOSCRIPT`
+    /// error would end the connection.
+    ///
+    /// [`xadd_idempotent`]: ./trait.StreamCommands.html#method.xadd_idempotent
+    ///
+    #[inline]
+    fn xadd_idempotent<K: ToRedisArgs, P: ToRedisArgs, F: ToRedisArgs, V: ToRedisArgs>(
+        self,
+        key: K,
+        producer_id: P,
+        seq: u64,
+        ttl: usize,
+        items: &[(F, V)],
+    ) -> RedisFuture<(Self, StreamEntryId)> {
+        let key = key.to_redis_args().concat();
+        let producer_id = producer_id.to_redis_args().concat();
+        cmd("EVAL")
+            .arg(XADD_IDEMPOTENT)
+            .arg(2)
+            .arg(&key[..])
+            .arg(dedup_key(&key, &producer_id, seq))
+            .arg(ttl)
+            .arg(items)
+            .arg(IdempotentProducer::PRODUCER_ID_FIELD)
+            .arg(&producer_id[..])
+            .arg(IdempotentProducer::SEQ_FIELD)
+            .arg(seq)
+            .query_async(self)
+    }
+
+    // XAUTOCLAIM <key> <group> <consumer> <min-idle-time> <start>
+
+    /// Async version of [`xautoclaim`].
+    ///
+    /// [`xautoclaim`]: ./trait.StreamCommands.html#method.xautoclaim
+    ///
+    #[inline]
+    fn xautoclaim<K: ToRedisArgs, G: ToRedisArgs, C: ToRedisArgs, MIT: ToRedisArgs>(
+        self,
+        key: K,
+        group: G,
+        consumer: C,
+        min_idle_time: MIT,
+        start: StreamEntryId,
+    ) -> RedisFuture<(Self, StreamAutoClaimReply)> {
+        cmd("XAUTOCLAIM")
+            .arg(key)
+            .arg(group)
+            .arg(consumer)
+            .arg(min_idle_time)
+            .arg(start)
+            .query_async(self)
+    }
+
+    // XAUTOCLAIM <key> <group> <consumer> <min-idle-time> <start>
+    //     [COUNT <count>] [JUSTID]
+
+    /// Async version of [`xautoclaim_options`].
+    ///
+    /// [`xautoclaim_options`]: ./trait.StreamCommands.html#method.xautoclaim_options
+    ///
+    #[inline]
+    fn xautoclaim_options<K: ToRedisArgs, G: ToRedisArgs, C: ToRedisArgs, MIT: ToRedisArgs>(
+        self,
+        key: K,
+        group: G,
+        consumer: C,
+        min_idle_time: MIT,
+        start: StreamEntryId,
+        options: StreamAutoClaimOptions,
+    ) -> RedisFuture<(Self, StreamAutoClaimReply)> {
+        cmd("XAUTOCLAIM")
+            .arg(key)
+            .arg(group)
+            .arg(consumer)
+            .arg(min_idle_time)
+            .arg(start)
+            .arg(options)
+            .query_async(self)
+    }
+
+    // XCLAIM <key> <group> <consumer> <min-idle-time> [<ID-1> <ID-2>]
+
+    /// Async version of [`xclaim`].
+    ///
+    /// [`xclaim`]: ./trait.StreamCommands.html#method.xclaim
+    ///
+    #[inline]
+    fn xclaim<K: ToRedisArgs, G: ToRedisArgs, C: ToRedisArgs, MIT: ToRedisArgs, ID: ToRedisArgs>(
+        self,
+        key: K,
+        group: G,
+        consumer: C,
+        min_idle_time: MIT,
+        ids: &[ID],
+    ) -> RedisFuture<(Self, StreamClaimReply)> {
+        cmd("XCLAIM")
+            .arg(key)
+            .arg(group)
+            .arg(consumer)
+            .arg(min_idle_time)
+            .arg(ids)
+            .query_async(self)
+    }
+
+    // XCLAIM <key> <group> <consumer> <min-idle-time> <ID-1> <ID-2>
+    //     [IDLE <milliseconds>] [TIME <mstime>] [RETRYCOUNT <count>]
+    //     [FORCE] [JUSTID]
+
+    /// Async version of [`xclaim_options`].
+    ///
+    /// [`xclaim_options`]: ./trait.StreamCommands.html#method.xclaim_options
+    ///
+    #[inline]
+    fn xclaim_options<
+        K: ToRedisArgs,
+        G: ToRedisArgs,
+        C: ToRedisArgs,
+        MIT: ToRedisArgs,
+        ID: ToRedisArgs,
+        RV: FromRedisValue + Send + 'static,
+    >(
+        self,
+        key: K,
+        group: G,
+        consumer: C,
+        min_idle_time: MIT,
+        ids: &[ID],
+        options: StreamClaimOptions,
+    ) -> RedisFuture<(Self, RV)> {
+        cmd("XCLAIM")
+            .arg(key)
+            .arg(group)
+            .arg(consumer)
+            .arg(min_idle_time)
+            .arg(ids)
+            .arg(options)
+            .query_async(self)
+    }
+
+    // XDEL <key> [<ID1> <ID2> ... <IDN>]
+
+    /// Async version of [`xdel`].
+    ///
+    /// [`xdel`]: ./trait.StreamCommands.html#method.xdel
+    ///
+    #[inline]
+    fn xdel<K: ToRedisArgs, ID: ToRedisArgs, RV: FromRedisValue + Send + 'static>(
+        self,
+        key: K,
+        ids: &[ID],
+    ) -> RedisFuture<(Self, RV)> {
+        cmd("XDEL").arg(key).arg(ids).query_async(self)
+    }
+
+    // XGROUP CREATE <key> <groupname> <id or $>
+
+    /// Async version of [`xgroup_create`].
+    ///
+    /// [`xgroup_create`]: ./trait.StreamCommands.html#method.xgroup_create
+    ///
+    #[inline]
+    fn xgroup_create<
+        K: ToRedisArgs,
+        G: ToRedisArgs,
+        ID: Into<ReadFrom>,
+        RV: FromRedisValue + Send + 'static,
+    >(
+        self,
+        key: K,
+        group: G,
+        id: ID,
+    ) -> RedisFuture<(Self, RV)> {
+        cmd("XGROUP")
+            .arg("CREATE")
+            .arg(key)
+            .arg(group)
+            .arg(id.into())
+            .query_async(self)
+    }
+
+    // XGROUP CREATE <key> <groupname> <id or $> [MKSTREAM]
+
+    /// Async version of [`xgroup_create_mkstream`].
+    ///
+    /// [`xgroup_create_mkstream`]: ./trait.StreamCommands.html#method.xgroup_create_mkstream
+    ///
+    #[inline]
+    fn xgroup_create_mkstream<
+        K: ToRedisArgs,
+        G: ToRedisArgs,
+        ID: Into<ReadFrom>,
+        RV: FromRedisValue + Send + 'static,
+    >(
+        self,
+        key: K,
+        group: G,
+        id: ID,
+    ) -> RedisFuture<(Self, RV)> {
+        cmd("XGROUP")
+            .arg("CREATE")
+            .arg(key)
+            .arg(group)
+            .arg(id.into())
+            .arg("MKSTREAM")
+            .query_async(self)
+    }
+
+    // XGROUP CREATE <key> <groupname> <id or $> [MKSTREAM] [ENTRIESREAD <entries_read>]
+
+    /// Async version of [`xgroup_create_options`].
+    ///
+    /// [`xgroup_create_options`]: ./trait.StreamCommands.html#method.xgroup_create_options
+    ///
+    #[inline]
+    fn xgroup_create_options<
+        K: ToRedisArgs,
+        G: ToRedisArgs,
+        RV: FromRedisValue + Send + 'static,
+    >(
+        self,
+        key: K,
+        group: G,
+        options: StreamGroupCreateOptions,
+    ) -> RedisFuture<(Self, RV)> {
+        cmd("XGROUP")
+            .arg("CREATE")
+            .arg(key)
+            .arg(group)
+            .arg(options)
+            .query_async(self)
+    }
+
+    // XGROUP SETID <key> <groupname> <id or $>
+
+    /// Async version of [`xgroup_setid`].
+    ///
+    /// [`xgroup_setid`]: ./trait.StreamCommands.html#method.xgroup_setid
+    ///
+    #[inline]
+    fn xgroup_setid<
+        K: ToRedisArgs,
+        G: ToRedisArgs,
+        ID: Into<ReadFrom>,
+        RV: FromRedisValue + Send + 'static,
+    >(
+        self,
+        key: K,
+        group: G,
+        id: ID,
+    ) -> RedisFuture<(Self, RV)> {
+        cmd("XGROUP")
+            .arg("SETID")
+            .arg(key)
+            .arg(group)
+            .arg(id.into())
+            .query_async(self)
+    }
+
+    // XGROUP SETID <key> <groupname> <id or $> ENTRIESREAD <entries_read>
+
+    /// Async version of [`xgroup_setid_entries_read`].
+    ///
+    /// [`xgroup_setid_entries_read`]: ./trait.StreamCommands.html#method.xgroup_setid_entries_read
+    ///
+    #[inline]
+    fn xgroup_setid_entries_read<
+        K: ToRedisArgs,
+        G: ToRedisArgs,
+        ID: Into<ReadFrom>,
+        RV: FromRedisValue + Send + 'static,
+    >(
+        self,
+        key: K,
+        group: G,
+        id: ID,
+        entries_read: usize,
+    ) -> RedisFuture<(Self, RV)> {
+        cmd("XGROUP")
+            .arg("SETID")
+            .arg(key)
+            .arg(group)
+            .arg(id.into())
+            .arg("ENTRIESREAD")
+            .arg(entries_read)
+            .query_async(self)
+    }
+
+    // XGROUP DESTROY <key> <groupname>
+
+    /// Async version of [`xgroup_destroy`].
+    ///
+    /// [`xgroup_destroy`]: ./trait.StreamCommands.html#method.xgroup_destroy
+    ///
+    #[inline]
+    fn xgroup_destroy<K: ToRedisArgs, G: ToRedisArgs, RV: FromRedisValue + Send + 'static>(
+        self,
+        key: K,
+        group: G,
+    ) -> RedisFuture<(Self, RV)> {
+        cmd("XGROUP")
+            .arg("DESTROY")
+            .arg(key)
+            .arg(group)
+            .query_async(self)
+    }
+
+    // XGROUP CREATECONSUMER <key> <groupname> <consumername>
+
+    /// Async version of [`xgroup_createconsumer`].
+    ///
+    /// [`xgroup_createconsumer`]: ./trait.StreamCommands.html#method.xgroup_createconsumer
+    ///
+    #[inline]
+    fn xgroup_createconsumer<
+        K: ToRedisArgs,
+        G: ToRedisArgs,
+        C: ToRedisArgs,
+        RV: FromRedisValue + Send + 'static,
+    >(
+        self,
+        key: K,
+        group: G,
+        consumer: C,
+    ) -> RedisFuture<(Self, RV)> {
+        cmd("XGROUP")
+            .arg("CREATECONSUMER")
+            .arg(key)
+            .arg(group)
+            .arg(consumer)
+            .query_async(self)
+    }
+
+    // XGROUP DELCONSUMER <key> <groupname> <consumername>
+
+    /// Async version of [`xgroup_delconsumer`].
+    ///
+    /// [`xgroup_delconsumer`]: ./trait.StreamCommands.html#method.xgroup_delconsumer
+    ///
+    #[inline]
+    fn xgroup_delconsumer<
+        K: ToRedisArgs,
+        G: ToRedisArgs,
+        C: ToRedisArgs,
+        RV: FromRedisValue + Send + 'static,
+    >(
+        self,
+        key: K,
+        group: G,
+        consumer: C,
+    ) -> RedisFuture<(Self, RV)> {
+        cmd("XGROUP")
+            .arg("DELCONSUMER")
+            .arg(key)
+            .arg(group)
+            .arg(consumer)
+            .query_async(self)
+    }
+
+    // XINFO CONSUMERS <key> <group>
+
+    /// Async version of [`xinfo_consumers`].
+    ///
+    /// [`xinfo_consumers`]: ./trait.StreamCommands.html#method.xinfo_consumers
+    ///
+    #[inline]
+    fn xinfo_consumers<K: ToRedisArgs, G: ToRedisArgs>(
+        self,
+        key: K,
+        group: G,
+    ) -> RedisFuture<(Self, StreamInfoConsumersReply)> {
+        cmd("XINFO")
+            .arg("CONSUMERS")
+            .arg(key)
+            .arg(group)
+            .query_async(self)
+    }
+
+    // XINFO GROUPS <key>
+
+    /// Async version of [`xinfo_groups`].
+    ///
+    /// [`xinfo_groups`]: ./trait.StreamCommands.html#method.xinfo_groups
+    ///
+    #[inline]
+    fn xinfo_groups<K: ToRedisArgs>(self, key: K) -> RedisFuture<(Self, StreamInfoGroupsReply)> {
+        cmd("XINFO").arg("GROUPS").arg(key).query_async(self)
+    }
+
+    // XINFO STREAM <key>
+
+    /// Async version of [`xinfo_stream`].
+    ///
+    /// [`xinfo_stream`]: ./trait.StreamCommands.html#method.xinfo_stream
+    ///
+    #[inline]
+    fn xinfo_stream<K: ToRedisArgs>(self, key: K) -> RedisFuture<(Self, StreamInfoStreamReply)> {
+        cmd("XINFO").arg("STREAM").arg(key).query_async(self)
+    }
+
+    // XINFO STREAM <key> FULL
+
+    /// Async version of [`xinfo_stream_full`].
+    ///
+    /// [`xinfo_stream_full`]: ./trait.StreamCommands.html#method.xinfo_stream_full
+    ///
+    #[inline]
+    fn xinfo_stream_full<K: ToRedisArgs>(
+        self,
+        key: K,
+    ) -> RedisFuture<(Self, StreamInfoStreamFullReply)> {
+        cmd("XINFO")
+            .arg("STREAM")
+            .arg(key)
+            .arg("FULL")
+            .query_async(self)
+    }
+
+    // XINFO STREAM <key> FULL COUNT <count>
+
+    /// Async version of [`xinfo_stream_full_count`].
+    ///
+    /// [`xinfo_stream_full_count`]: ./trait.StreamCommands.html#method.xinfo_stream_full_count
+    ///
+    #[inline]
+    fn xinfo_stream_full_count<K: ToRedisArgs, C: ToRedisArgs>(
+        self,
+        key: K,
+        count: C,
+    ) -> RedisFuture<(Self, StreamInfoStreamFullReply)> {
+        cmd("XINFO")
+            .arg("STREAM")
+            .arg(key)
+            .arg("FULL")
+            .arg("COUNT")
+            .arg(count)
+            .query_async(self)
+    }
+
+    // XLEN <key>
+
+    /// Async version of [`xlen`].
+    ///
+    /// [`xlen`]: ./trait.StreamCommands.html#method.xlen
+    ///
+    #[inline]
+    fn xlen<K: ToRedisArgs, RV: FromRedisValue + Send + 'static>(
+        self,
+        key: K,
+    ) -> RedisFuture<(Self, RV)> {
+        cmd("XLEN").arg(key).query_async(self)
+    }
+
+    // XPENDING <key> <group> [<start> <stop> <count> [<consumer>]]
+
+    /// Async version of [`xpending`].
+    ///
+    /// [`xpending`]: ./trait.StreamCommands.html#method.xpending
+    ///
+    #[inline]
+    fn xpending<K: ToRedisArgs, G: ToRedisArgs>(
+        self,
+        key: K,
+        group: G,
+    ) -> RedisFuture<(Self, StreamPendingReply)> {
+        cmd("XPENDING").arg(key).arg(group).query_async(self)
+    }
+
+    // XPENDING <key> <group> <start> <stop> <count>
+
+    /// Async version of [`xpending_count`].
+    ///
+    /// [`xpending_count`]: ./trait.StreamCommands.html#method.xpending_count
+    ///
+    #[inline]
+    fn xpending_count<
+        K: ToRedisArgs,
+        G: ToRedisArgs,
+        S: Into<RangeBound>,
+        E: Into<RangeBound>,
+        C: ToRedisArgs,
+    >(
+        self,
+        key: K,
+        group: G,
+        start: S,
+        end: E,
+        count: C,
+    ) -> RedisFuture<(Self, StreamPendingCountReply)> {
+        cmd("XPENDING")
+            .arg(key)
+            .arg(group)
+            .arg(start.into())
+            .arg(end.into())
+            .arg(count)
+            .query_async(self)
+    }
+
+    // XPENDING <key> <group> <start> <stop> <count> <consumer>
+
+    /// Async version of [`xpending_consumer_count`].
+    ///
+    /// [`xpending_consumer_count`]: ./trait.StreamCommands.html#method.xpending_consumer_count
+    ///
+    #[inline]
+    fn xpending_consumer_count<
+        K: ToRedisArgs,
+        G: ToRedisArgs,
+        S: Into<RangeBound>,
+        E: Into<RangeBound>,
+        C: ToRedisArgs,
+        CN: ToRedisArgs,
+    >(
+        self,
+        key: K,
+        group: G,
+        start: S,
+        end: E,
+        count: C,
+        consumer: CN,
+    ) -> RedisFuture<(Self, StreamPendingCountReply)> {
+        cmd("XPENDING")
+            .arg(key)
+            .arg(group)
+            .arg(start.into())
+            .arg(end.into())
+            .arg(count)
+            .arg(consumer)
+            .query_async(self)
+    }
+
+    // XPENDING <key> <group> IDLE <min-idle-time> <start> <stop> <count>
+
+    /// Async version of [`xpending_idle_count`].
+    ///
+    /// [`xpending_idle_count`]: ./trait.StreamCommands.html#method.xpending_idle_count
+    ///
+    #[inline]
+    fn xpending_idle_count<
+        K: ToRedisArgs,
+        G: ToRedisArgs,
+        MIT: ToRedisArgs,
+        S: Into<RangeBound>,
+        E: Into<RangeBound>,
+        C: ToRedisArgs,
+    >(
+        self,
+        key: K,
+        group: G,
+        min_idle_time: MIT,
+        start: S,
+        end: E,
+        count: C,
+    ) -> RedisFuture<(Self, StreamPendingCountReply)> {
+        cmd("XPENDING")
+            .arg(key)
+            .arg(group)
+            .arg("IDLE")
+            .arg(min_idle_time)
+            .arg(start.into())
+            .arg(end.into())
+            .arg(count)
+            .query_async(self)
+    }
+
+    // XPENDING <key> <group> [IDLE <min-idle-time>] <start> <stop> <count> [<consumer>]
+
+    /// Async version of [`xpending_options`].
+    ///
+    /// [`xpending_options`]: ./trait.StreamCommands.html#method.xpending_options
+    ///
+    #[inline]
+    fn xpending_options<K: ToRedisArgs, G: ToRedisArgs>(
+        self,
+        key: K,
+        group: G,
+        options: StreamPendingOptions,
+    ) -> RedisFuture<(Self, StreamPendingCountReply)> {
+        cmd("XPENDING")
+            .arg(key)
+            .arg(group)
+            .arg(options)
+            .query_async(self)
+    }
+
+    // XRANGE key start end
+
+    /// Async version of [`xrange`].
+    ///
+    /// [`xrange`]: ./trait.StreamCommands.html#method.xrange
+    ///
+    #[inline]
+    fn xrange<K: ToRedisArgs, S: Into<RangeBound>, E: Into<RangeBound>>(
+        self,
+        key: K,
+        start: S,
+        end: E,
+    ) -> RedisFuture<(Self, StreamRangeReply)> {
+        cmd("XRANGE")
+            .arg(key)
+            .arg(start.into())
+            .arg(end.into())
+            .query_async(self)
+    }
+
+    // XRANGE key start end
+
+    /// Async version of [`xrange_bytes`].
+    ///
+    /// [`xrange_bytes`]: ./trait.StreamCommands.html#method.xrange_bytes
+    ///
+    #[inline]
+    fn xrange_bytes<K: ToRedisArgs, S: Into<RangeBound>, E: Into<RangeBound>>(
+        self,
+        key: K,
+        start: S,
+        end: E,
+    ) -> RedisFuture<(Self, StreamRangeReply<Vec<u8>>)> {
+        cmd("XRANGE")
+            .arg(key)
+            .arg(start.into())
+            .arg(end.into())
+            .query_async(self)
+    }
+
+    // XRANGE key - +
+
+    /// Async version of [`xrange_all`].
+    ///
+    /// [`xrange_all`]: ./trait.StreamCommands.html#method.xrange_all
+    ///
+    #[inline]
+    fn xrange_all<K: ToRedisArgs, RV: FromRedisValue + Send + 'static>(
+        self,
+        key: K,
+    ) -> RedisFuture<(Self, RV)> {
+        cmd("XRANGE")
+            .arg(key)
+            .arg(RangeBound::Min)
+            .arg(RangeBound::Max)
+            .query_async(self)
+    }
+
+    // XRANGE key start end [COUNT <n>]
+
+    /// Async version of [`xrange_count`].
+    ///
+    /// [`xrange_count`]: ./trait.StreamCommands.html#method.xrange_count
+    ///
+    #[inline]
+    fn xrange_count<K: ToRedisArgs, S: Into<RangeBound>, E: Into<RangeBound>, C: ToRedisArgs>(
+        self,
+        key: K,
+        start: S,
+        end: E,
+        count: C,
+    ) -> RedisFuture<(Self, StreamRangeReply)> {
+        cmd("XRANGE")
+            .arg(key)
+            .arg(start.into())
+            .arg(end.into())
+            .arg("COUNT")
+            .arg(count)
+            .query_async(self)
+    }
+
+    // XREAD STREAMS key_1 key_2 ... key_N ID_1 ID_2 ... ID_N
+
+    /// Async version of [`xread`].
+    ///
+    /// [`xread`]: ./trait.StreamCommands.html#method.xread
+    ///
+    #[inline]
+    fn xread<K: ToRedisArgs>(
+        self,
+        keys: &[K],
+        ids: &[ReadFrom],
+    ) -> RedisFuture<(Self, StreamReadReply)> {
+        cmd("XREAD")
+            .arg("STREAMS")
+            .arg(keys)
+            .arg(ids)
+            .query_async(self)
+    }
+
+    // XREAD [BLOCK <milliseconds>] [COUNT <count>]
+    //       STREAMS key_1 key_2 ... key_N
+    //       ID_1 ID_2 ... ID_N
+    // XREADGROUP [BLOCK <milliseconds>] [COUNT <count>] [GROUP group-name consumer-name]
+    //       STREAMS key_1 key_2 ... key_N
+    //       ID_1 ID_2 ... ID_N
+
+    /// Async version of [`xread_options`].
+    ///
+    /// [`xread_options`]: ./trait.StreamCommands.html#method.xread_options
+    ///
+    #[inline]
+    fn xread_options<K: ToRedisArgs, G: StreamReadMode>(
+        self,
+        keys: &[K],
+        ids: &[G::Id],
+        options: StreamReadOptions<G>,
+    ) -> RedisFuture<(Self, StreamReadReply)> {
+        cmd(G::COMMAND)
+            .arg(options)
+            .arg("STREAMS")
+            .arg(keys)
+            .arg(ids)
+            .query_async(self)
+    }
+
+    // XREAD STREAMS key_1 key_2 ... key_N ID_1 ID_2 ... ID_N
+
+    /// Async version of [`xread_bytes`].
+    ///
+    /// [`xread_bytes`]: ./trait.StreamCommands.html#method.xread_bytes
+    ///
+    #[inline]
+    fn xread_bytes<K: ToRedisArgs>(
+        self,
+        keys: &[K],
+        ids: &[ReadFrom],
+    ) -> RedisFuture<(Self, StreamReadBytesReply)> {
+        cmd("XREAD")
+            .arg("STREAMS")
+            .arg(keys)
+            .arg(ids)
+            .query_async(self)
+    }
+
+    // XREAD [BLOCK <milliseconds>] [COUNT <count>]
+    //       STREAMS key_1 key_2 ... key_N
+    //       ID_1 ID_2 ... ID_N
+    // XREADGROUP [BLOCK <milliseconds>] [COUNT <count>] [GROUP group-name consumer-name]
+    //       STREAMS key_1 key_2 ... key_N
+    //       ID_1 ID_2 ... ID_N
+
+    /// Async version of [`xread_options_bytes`].
+    ///
+    /// [`xread_options_bytes`]: ./trait.StreamCommands.html#method.xread_options_bytes
+    ///
+    #[inline]
+    fn xread_options_bytes<K: ToRedisArgs, G: StreamReadMode>(
+        self,
+        keys: &[K],
+        ids: &[G::Id],
+        options: StreamReadOptions<G>,
+    ) -> RedisFuture<(Self, StreamReadBytesReply)> {
+        cmd(G::COMMAND)
+            .arg(options)
+            .arg("STREAMS")
+            .arg(keys)
+            .arg(ids)
+            .query_async(self)
+    }
+
+    // XREAD BLOCK <milliseconds> COUNT <count> STREAMS key_1 ... key_N ID_1 ... ID_N (repeated)
+
+    /// Returns a `Stream` which tails each stream in `keys`, starting after
+    /// the matching position in `ids`. This is the async version of [`xread_iter`].
+    ///
+    /// [`xread_iter`]: ./trait.StreamCommands.html#method.xread_iter
+    ///
+    #[inline]
+    fn xread_stream<K: ToRedisArgs>(
+        self,
+        keys: &[K],
+        ids: &[ReadFrom],
+        block: usize,
+        count: usize,
+    ) -> StreamTail<Self> {
+        StreamTail::new(self, keys, ids, block, count)
+    }
+
+    // XREAD STREAMS key_1 key_2 ... key_N ID_1 ID_2 ... ID_N
+
+    /// Async version of [`xread_typed`].
+    ///
+    /// [`xread_typed`]: ./trait.StreamCommands.html#method.xread_typed
+    ///
+    #[inline]
+    fn xread_typed<T: FromStreamEntry + Send + 'static, K: ToRedisArgs>(
+        self,
+        keys: &[K],
+        ids: &[ReadFrom],
+    ) -> RedisFuture<(Self, StreamTypedReply<T>)> {
+        cmd("XREAD")
+            .arg("STREAMS")
+            .arg(keys)
+            .arg(ids)
+            .query_async(self)
+    }
+
+    // XREAD [BLOCK <milliseconds>] [COUNT <count>]
+    //       STREAMS key_1 key_2 ... key_N
+    //       ID_1 ID_2 ... ID_N
+    // XREADGROUP [BLOCK <milliseconds>] [COUNT <count>] [GROUP group-name consumer-name]
+    //       STREAMS key_1 key_2 ... key_N
+    //       ID_1 ID_2 ... ID_N
+
+    /// Async version of [`xread_typed_options`].
+    ///
+    /// [`xread_typed_options`]: ./trait.StreamCommands.html#method.xread_typed_options
+    ///
+    #[inline]
+    fn xread_typed_options<
+        T: FromStreamEntry + Send + 'static,
+        K: ToRedisArgs,
+        G: StreamReadMode,
+    >(
+        self,
+        keys: &[K],
+        ids: &[G::Id],
+        options: StreamReadOptions<G>,
+    ) -> RedisFuture<(Self, StreamTypedReply<T>)> {
+        cmd(G::COMMAND)
+            .arg(options)
+            .arg("STREAMS")
+            .arg(keys)
+            .arg(ids)
+            .query_async(self)
+    }
+
+    // XREVRANGE key end start
+
+    /// Async version of [`xrevrange`].
+    ///
+    /// [`xrevrange`]: ./trait.StreamCommands.html#method.xrevrange
+    ///
+    #[inline]
+    fn xrevrange<K: ToRedisArgs, E: Into<RangeBound>, S: Into<RangeBound>>(
+        self,
+        key: K,
+        end: E,
+        start: S,
+    ) -> RedisFuture<(Self, StreamRangeReply)> {
+        cmd("XREVRANGE")
+            .arg(key)
+            .arg(end.into())
+            .arg(start.into())
+            .query_async(self)
+    }
+
+    // XREVRANGE key + -
+
+    /// Async version of [`xrevrange_all`].
+    ///
+    /// [`xrevrange_all`]: ./trait.StreamCommands.html#method.xrevrange_all
+    ///
+    #[inline]
+    fn xrevrange_all<K: ToRedisArgs>(self, key: K) -> RedisFuture<(Self, StreamRangeReply)> {
+        cmd("XREVRANGE")
+            .arg(key)
+            .arg(RangeBound::Max)
+            .arg(RangeBound::Min)
+            .query_async(self)
+    }
+
+    // XREVRANGE key end start [COUNT <n>]
+
+    /// Async version of [`xrevrange_count`].
+    ///
+    /// [`xrevrange_count`]: ./trait.StreamCommands.html#method.xrevrange_count
+    ///
+    #[inline]
+    fn xrevrange_count<K: ToRedisArgs, E: Into<RangeBound>, S: Into<RangeBound>, C: ToRedisArgs>(
+        self,
+        key: K,
+        end: E,
+        start: S,
+        count: C,
+    ) -> RedisFuture<(Self, StreamRangeReply)> {
+        cmd("XREVRANGE")
+            .arg(key)
+            .arg(end.into())
+            .arg(start.into())
+            .arg("COUNT")
+            .arg(count)
+            .query_async(self)
+    }
+
+    // XSETID <key> <last-id>
+
+    /// Async version of [`xsetid`].
+    ///
+    /// [`xsetid`]: ./trait.StreamCommands.html#method.xsetid
+    ///
+    #[inline]
+    fn xsetid<K: ToRedisArgs, RV: FromRedisValue + Send + 'static>(
+        self,
+        key: K,
+        id: StreamEntryId,
+    ) -> RedisFuture<(Self, RV)> {
+        cmd("XSETID").arg(key).arg(id).query_async(self)
+    }
+
+    // XSETID <key> <last-id> [ENTRIESADDED <entries_added>] [MAXDELETEDID <max_deleted_id>]
+
+    /// Async version of [`xsetid_options`].
+    ///
+    /// [`xsetid_options`]: ./trait.StreamCommands.html#method.xsetid_options
+    ///
+    #[inline]
+    fn xsetid_options<K: ToRedisArgs, RV: FromRedisValue + Send + 'static>(
+        self,
+        key: K,
+        id: StreamEntryId,
+        options: StreamSetIdOptions,
+    ) -> RedisFuture<(Self, RV)> {
+        cmd("XSETID")
+            .arg(key)
+            .arg(id)
+            .arg(options)
+            .query_async(self)
+    }
+
+    // XTRIM <key> MAXLEN|MINID [~|=] <threshold> [LIMIT <count>]  (Same as XADD trim option)
+
+    /// Async version of [`xtrim`].
+    ///
+    /// [`xtrim`]: ./trait.StreamCommands.html#method.xtrim
+    ///
+    #[inline]
+    fn xtrim<K: ToRedisArgs, T: Into<StreamTrimStrategy>, RV: FromRedisValue + Send + 'static>(
+        self,
+        key: K,
+        trim: T,
+    ) -> RedisFuture<(Self, RV)> {
+        cmd("XTRIM").arg(key).arg(trim.into()).query_async(self)
+    }
+}
+
+impl<T> AsyncStreamCommands for T where T: ConnectionLike + Send + Sized + 'static {}
@@ -1,10 +1,20 @@
+use crate::entry::FromStreamEntry;
+use crate::error::StreamError;
+use crate::idempotent::{dedup_key, IdempotentProducer};
+use crate::iter::{StreamAutoClaimIter, StreamRangeIter};
+use crate::pipeline::StreamPipelineExt;
+use crate::scripts::{XACK_AND_ADD, XADD_IDEMPOTENT};
+use crate::tail::StreamTailIter;
 use crate::types::{
-    StreamClaimOptions, StreamClaimReply, StreamInfoConsumersReply, StreamInfoGroupsReply,
-    StreamInfoStreamReply, StreamMaxlen, StreamPendingCountReply, StreamPendingReply,
-    StreamRangeReply, StreamReadOptions, StreamReadReply,
+    AddId, RangeBound, ReadFrom, StreamAddOptions, StreamAutoClaimOptions, StreamAutoClaimReply,
+    StreamClaimOptions, StreamClaimReply, StreamEntryId, StreamGroupCreateOptions,
+    StreamInfoConsumersReply, StreamInfoGroupsReply, StreamInfoStreamFullReply,
+    StreamInfoStreamReply, StreamPendingCountReply, StreamPendingOptions, StreamPendingReply,
+    StreamRangeReply, StreamReadBytesReply, StreamReadMode, StreamReadOptions, StreamReadReply,
+    StreamSetIdOptions, StreamTrimStrategy, StreamTypedReply,
 };
 
-use redis::{cmd, ConnectionLike, FromRedisValue, RedisResult, ToRedisArgs};
+use redis::{cmd, ConnectionLike, FromRedisValue, RedisResult, Script, ToRedisArgs};
 
 /// Implementation of all redis stream commands.
 ///
@@ -23,81 +33,349 @@ pub trait StreamCommands: ConnectionLike + Sized {
         cmd("XACK").arg(key).arg(group).arg(ids).query(self)
     }
 
+    // XACK <src_key> <group> <id> + XADD <dst_key> * [field value] ... (Lua script)
+
+    /// Ack `id` on the stream `src_key` and add `fields` to the stream
+    /// `dst_key` in one step, with a server side script. This forwards
+    /// an entry from one processing stage to the next without a crash
+    /// in between dropping or duplicating it.
+    ///
+    /// The entry is only added if `id` was still pending for `group`, so
+    /// calling this again for the same `id` (e.g. when retrying after a
+    /// timeout) doesn't add it twice. Returns the new id, or `None` if
+    /// nothing was pending.
+    ///
+    /// In a cluster, both keys need to hash to the same slot. Use
+    /// [`xack_and_add_multi`] where scripts aren't allowed.
+    ///
+    /// ```no_run
+    /// use redis_streams::{client_open,Connection,StreamCommands,StreamEntryId};
+    /// let client = client_open("redis://127.0.0.1/0").unwrap();
+    /// let mut con = client.get_connection().unwrap();
+    ///
+    /// let id = StreamEntryId::new(1000, 0);
+    /// let forwarded = con
+    ///     .xack_and_add("{orders}:new", "billing", id, "{orders}:billed", &[("order", "1")])
+    ///     .unwrap();
+    /// ```
+    ///
+    /// [`xack_and_add_multi`]: #method.xack_and_add_multi
+    ///
+    #[inline]
+    fn xack_and_add<
+        S: ToRedisArgs,
+        G: ToRedisArgs,
+        D: ToRedisArgs,
+        F: ToRedisArgs,
+        V: ToRedisArgs,
+    >(
+        &mut self,
+        src_key: S,
+        group: G,
+        id: StreamEntryId,
+        dst_key: D,
+        fields: &[(F, V)],
+    ) -> RedisResult<Option<StreamEntryId>> {
+        Script::new(XACK_AND_ADD)
+            .key(src_key)
+            .key(dst_key)
+            .arg(group)
+            .arg(id)
+            .arg(fields)
+            .invoke(self)
+    }
+
+    // MULTI
+    // XACK <src_key> <group> <id>
+    // XADD <dst_key> * [field value] ...
+    // EXEC
+
+    /// The `MULTI`/`EXEC` version of [`xack_and_add`], for servers
+    /// where scripts aren't available.
+    ///
+    /// Both commands still happen atomically, but a transaction can't
+    /// check the `XACK` result, so the entry is added even if `id`
+    /// was no longer pending. Returns the new id.
+    ///
+    /// [`xack_and_add`]: #method.xack_and_add
+    ///
+    #[inline]
+    fn xack_and_add_multi<
+        S: ToRedisArgs,
+        G: ToRedisArgs,
+        D: ToRedisArgs,
+        F: ToRedisArgs,
+        V: ToRedisArgs,
+    >(
+        &mut self,
+        src_key: S,
+        group: G,
+        id: StreamEntryId,
+        dst_key: D,
+        fields: &[(F, V)],
+    ) -> RedisResult<StreamEntryId> {
+        let (new_id,): (StreamEntryId,) = redis::pipe()
+            .atomic()
+            .xack(src_key, group, &[id])
+            .ignore()
+            .xadd(dst_key, AddId::Auto, fields)
+            .query(self)?;
+        Ok(new_id)
+    }
+
     // XADD key <ID or *> [field value] [field value] ...
 
-    /// Add a stream message by `key`. Use `*` as the `id` for the current timestamp.
+    /// Add a stream message by `key`. Use `AddId::Auto` as the `id` for the current timestamp.
+    /// The generated id can be returned as a `StreamEntryId`.
+    /// See `xadd_options` for `NOMKSTREAM` and trimming.
     ///
     #[inline]
-    fn xadd<K: ToRedisArgs, ID: ToRedisArgs, F: ToRedisArgs, V: ToRedisArgs, RV: FromRedisValue>(
+    fn xadd<K: ToRedisArgs, ID: Into<AddId>, F: ToRedisArgs, V: ToRedisArgs, RV: FromRedisValue>(
         &mut self,
         key: K,
         id: ID,
         items: &[(F, V)],
     ) -> RedisResult<RV> {
-        cmd("XADD").arg(key).arg(id).arg(items).query(self)
+        cmd("XADD").arg(key).arg(id.into()).arg(items).query(self)
     }
 
     // XADD key <ID or *> [rust BTreeMap] ...
 
     /// BTreeMap variant for adding a stream message by `key`.
-    /// Use `*` as the `id` for the current timestamp.
+    /// Use `AddId::Auto` as the `id` for the current timestamp.
     ///
     #[inline]
-    fn xadd_map<K: ToRedisArgs, ID: ToRedisArgs, BTM: ToRedisArgs, RV: FromRedisValue>(
+    fn xadd_map<K: ToRedisArgs, ID: Into<AddId>, BTM: ToRedisArgs, RV: FromRedisValue>(
         &mut self,
         key: K,
         id: ID,
         map: BTM,
     ) -> RedisResult<RV> {
-        cmd("XADD").arg(key).arg(id).arg(map).query(self)
+        cmd("XADD").arg(key).arg(id.into()).arg(map).query(self)
     }
 
-    // XADD key [MAXLEN [~|=] <count>] <ID or *> [field value] [field value] ...
+    // XADD key [MAXLEN|MINID [~|=] <threshold> [LIMIT <count>]] <ID or *> [field value] ...
 
-    /// Add a stream message while capping the stream at a maxlength.
+    /// Add a stream message while trimming the stream.
+    /// Pass a `StreamMaxlen` to cap the stream at a maxlength,
+    /// or a `StreamTrimStrategy` for `MINID` and `LIMIT` support.
     ///
     #[inline]
     fn xadd_maxlen<
         K: ToRedisArgs,
-        ID: ToRedisArgs,
+        T: Into<StreamTrimStrategy>,
+        ID: Into<AddId>,
         F: ToRedisArgs,
         V: ToRedisArgs,
         RV: FromRedisValue,
     >(
         &mut self,
         key: K,
-        maxlen: StreamMaxlen,
+        trim: T,
         id: ID,
         items: &[(F, V)],
     ) -> RedisResult<RV> {
         cmd("XADD")
             .arg(key)
-            .arg(maxlen)
-            .arg(id)
+            .arg(trim.into())
+            .arg(id.into())
             .arg(items)
             .query(self)
     }
 
-    // XADD key [MAXLEN [~|=] <count>] <ID or *> [rust BTreeMap] ...
+    // XADD key [MAXLEN|MINID [~|=] <threshold> [LIMIT <count>]] <ID or *> [rust BTreeMap] ...
 
-    /// BTreeMap variant for adding a stream message while capping the stream at a maxlength.
+    /// BTreeMap variant for adding a stream message while trimming the stream.
     ///
     #[inline]
-    fn xadd_maxlen_map<K: ToRedisArgs, ID: ToRedisArgs, BTM: ToRedisArgs, RV: FromRedisValue>(
+    fn xadd_maxlen_map<
+        K: ToRedisArgs,
+        T: Into<StreamTrimStrategy>,
+        ID: Into<AddId>,
+        BTM: ToRedisArgs,
+        RV: FromRedisValue,
+    >(
         &mut self,
         key: K,
-        maxlen: StreamMaxlen,
+        trim: T,
         id: ID,
         map: BTM,
     ) -> RedisResult<RV> {
         cmd("XADD")
             .arg(key)
-            .arg(maxlen)
-            .arg(id)
+            .arg(trim.into())
+            .arg(id.into())
             .arg(map)
             .query(self)
     }
 
+    // XADD key [NOMKSTREAM] [MAXLEN|MINID [~|=] <threshold> [LIMIT <count>]]
+    //     <ID or *> [field value] [field value] ...
+
+    /// This is the optional arguments version for adding a stream message by `key`.
+    ///
+    /// The reply is the `id` of the new message, or `None` when `NOMKSTREAM`
+    /// is set and the stream doesn't exist.
+    ///
+    /// ```no_run
+    /// use redis_streams::{client_open,RedisResult,StreamAddOptions,StreamCommands,StreamEntryId,StreamMaxlen};
+    /// let client = client_open("redis://127.0.0.1/0").unwrap();
+    /// let mut con = client.get_connection().unwrap();
+    ///
+    /// // Add a message to "k1" only if the stream
+    /// // already exists, capping it around 1000 messages.
+    ///
+    /// let opts = StreamAddOptions::default()
+    ///     .nomkstream()
+    ///     .trim(StreamMaxlen::Aprrox(1000));
+    /// let id: RedisResult<Option<StreamEntryId>> =
+    ///     con.xadd_options("k1", &[("hello", "world")], opts);
+    /// ```
+    ///
+    #[inline]
+    fn xadd_options<K: ToRedisArgs, F: ToRedisArgs, V: ToRedisArgs>(
+        &mut self,
+        key: K,
+        items: &[(F, V)],
+        options: StreamAddOptions,
+    ) -> RedisResult<Option<StreamEntryId>> {
+        cmd("XADD").arg(key).arg(options).arg(items).query(self)
+    }
+
+    // XADD key * [field value] ... producer-id <producer_id> producer-seq <seq> (Lua script)
+
+    /// Add a stream message by `key` at most once for each `producer_id`
+    /// and `seq`, so retrying after a timeout doesn't add it twice.
+    /// Returns the id of the entry, which is the id of the original
+    /// entry on a repeat.
+    ///
+    /// The entry gets two more fields, `producer-id` and `producer-seq`.
+    /// A `<key>:dedup:<producer_id>:<seq>` key holds the id for `ttl` ms,
+    /// which bounds how late a retry can be, so `ttl` has to be above 0.
+    /// In a cluster, use a hash tag in `key` so both keys hash to the
+    /// same slot.
+    ///
+    /// See [`IdempotentProducer`] to keep track of sequence numbers.
+    ///
+    /// ```no_run
+    /// use redis_streams::{client_open,Connection,StreamCommands};
+    /// let client = client_open("redis://127.0.0.1/0").unwrap();
+    /// let mut con = client.get_connection().unwrap();
+    ///
+    /// let first = con.xadd_idempotent("k1", "p1", 1, 60000, &[("f", "v")]).unwrap();
+    /// let again = con.xadd_idempotent("k1", "p1", 1, 60000, &[("f", "v")]).unwrap();
+    /// assert_eq!(first, again);
+    /// ```
+    ///
+    /// [`IdempotentProducer`]: ./struct.IdempotentProducer.html
+    ///
+    #[inline]
+    fn xadd_idempotent<K: ToRedisArgs, P: ToRedisArgs, F: ToRedisArgs, V: ToRedisArgs>(
+        &mut self,
+        key: K,
+        producer_id: P,
+        seq: u64,
+        ttl: usize,
+        items: &[(F, V)],
+    ) -> RedisResult<StreamEntryId> {
+        let key = key.to_redis_args().concat();
+        let producer_id = producer_id.to_redis_args().concat();
+        Script::new(XADD_IDEMPOTENT)
+            .key(&key[..])
+            .key(dedup_key(&key, &producer_id, seq))
+            .arg(ttl)
+            .arg(items)
+            .arg(IdempotentProducer::PRODUCER_ID_FIELD)
+            .arg(&producer_id[..])
+            .arg(IdempotentProducer::SEQ_FIELD)
+            .arg(seq)
+            .invoke(self)
+    }
+
+    // XAUTOCLAIM <key> <group> <consumer> <min-idle-time> <start>
+
+    /// Claim pending messages idle for longer than `min_idle_time`
+    /// without having to look them up with `xpending_count` first.
+    ///
+    /// Scanning begins at the `start` id (`StreamEntryId::MIN` for the whole
+    /// pending entries list) and the reply's `next_cursor` is where the next
+    /// call should continue from. See `xautoclaim_iter` to walk the cursor automatically.
+    ///
+    /// Take note of the StreamAutoClaimReply return type.
+    ///
+    #[inline]
+    fn xautoclaim<K: ToRedisArgs, G: ToRedisArgs, C: ToRedisArgs, MIT: ToRedisArgs>(
+        &mut self,
+        key: K,
+        group: G,
+        consumer: C,
+        min_idle_time: MIT,
+        start: StreamEntryId,
+    ) -> RedisResult<StreamAutoClaimReply> {
+        cmd("XAUTOCLAIM")
+            .arg(key)
+            .arg(group)
+            .arg(consumer)
+            .arg(min_idle_time)
+            .arg(start)
+            .query(self)
+    }
+
+    // XAUTOCLAIM <key> <group> <consumer> <min-idle-time> <start>
+    //     [COUNT <count>] [JUSTID]
+
+    /// This is the optional arguments version of `xautoclaim`.
+    ///
+    /// ```no_run
+    /// use redis_streams::{client_open,RedisResult,StreamCommands,StreamAutoClaimOptions,StreamAutoClaimReply,StreamEntryId};
+    /// let client = client_open("redis://127.0.0.1/0").unwrap();
+    /// let mut con = client.get_connection().unwrap();
+    ///
+    /// // Claim up to 10 messages for key "k1", from group "g1",
+    /// // which have been idle for at least 1000ms, for consumer "c2".
+    ///
+    /// let opts = StreamAutoClaimOptions::default().count(10);
+    /// let reply: RedisResult<StreamAutoClaimReply> =
+    ///     con.xautoclaim_options("k1", "g1", "c2", 1000, StreamEntryId::MIN, opts);
+    /// ```
+    ///
+    #[inline]
+    fn xautoclaim_options<K: ToRedisArgs, G: ToRedisArgs, C: ToRedisArgs, MIT: ToRedisArgs>(
+        &mut self,
+        key: K,
+        group: G,
+        consumer: C,
+        min_idle_time: MIT,
+        start: StreamEntryId,
+        options: StreamAutoClaimOptions,
+    ) -> RedisResult<StreamAutoClaimReply> {
+        cmd("XAUTOCLAIM")
+            .arg(key)
+            .arg(group)
+            .arg(consumer)
+            .arg(min_idle_time)
+            .arg(start)
+            .arg(options)
+            .query(self)
+    }
+
+    // XAUTOCLAIM <key> <group> <consumer> <min-idle-time> <cursor> (repeated)
+
+    /// Returns an iterator over every message claimed by repeatedly calling
+    /// `xautoclaim_options` until the whole pending entries list has been scanned.
+    ///
+    #[inline]
+    fn xautoclaim_iter<K: ToRedisArgs, G: ToRedisArgs, C: ToRedisArgs, MIT: ToRedisArgs>(
+        &mut self,
+        key: K,
+        group: G,
+        consumer: C,
+        min_idle_time: MIT,
+        options: StreamAutoClaimOptions,
+    ) -> StreamAutoClaimIter<'_, Self> {
+        StreamAutoClaimIter::new(self, key, group, consumer, min_idle_time, options)
+    }
+
     // XCLAIM <key> <group> <consumer> <min-idle-time> [<ID-1> <ID-2>]
 
     /// Claim pending, unacked messages, after some period of time,
@@ -199,11 +477,11 @@ pub trait StreamCommands: ConnectionLike + Sized {
 
     /// This command is used for creating a consumer `group`. It expects the stream key
     /// to already exist. Otherwise, use `xgroup_create_mkstream` if it doesn't.
-    /// The `id` is the starting message id all consumers should read from. Use `$` If you want
-    /// all consumers to read from the last message added to stream.
+    /// The `id` is the starting message id all consumers should read from. Use `ReadFrom::New`
+    /// If you want all consumers to read from the last message added to stream.
     ///
     #[inline]
-    fn xgroup_create<K: ToRedisArgs, G: ToRedisArgs, ID: ToRedisArgs, RV: FromRedisValue>(
+    fn xgroup_create<K: ToRedisArgs, G: ToRedisArgs, ID: Into<ReadFrom>, RV: FromRedisValue>(
         &mut self,
         key: K,
         group: G,
@@ -213,7 +491,7 @@ pub trait StreamCommands: ConnectionLike + Sized {
             .arg("CREATE")
             .arg(key)
             .arg(group)
-            .arg(id)
+            .arg(id.into())
             .query(self)
     }
 
@@ -226,7 +504,7 @@ pub trait StreamCommands: ConnectionLike + Sized {
     fn xgroup_create_mkstream<
         K: ToRedisArgs,
         G: ToRedisArgs,
-        ID: ToRedisArgs,
+        ID: Into<ReadFrom>,
         RV: FromRedisValue,
     >(
         &mut self,
@@ -238,18 +516,85 @@ pub trait StreamCommands: ConnectionLike + Sized {
             .arg("CREATE")
             .arg(key)
             .arg(group)
-            .arg(id)
+            .arg(id.into())
             .arg("MKSTREAM")
             .query(self)
     }
 
+    // XGROUP CREATE <key> <groupname> <id or $> [MKSTREAM] [ENTRIESREAD <entries_read>]
+
+    /// The options version of `xgroup_create`, which also sets `MKSTREAM`
+    /// and `ENTRIESREAD`. Setting the number of entries already read keeps
+    /// the group's `lag` correct when it doesn't start from the beginning.
+    ///
+    /// ```no_run
+    /// use redis_streams::{client_open,Connection,RedisResult,StreamCommands,StreamEntryId,StreamGroupCreateOptions};
+    /// let client = client_open("redis://127.0.0.1/0").unwrap();
+    /// let mut con = client.get_connection().unwrap();
+    ///
+    /// let opts = StreamGroupCreateOptions::default()
+    ///     .start(StreamEntryId::new(1000, 0))
+    ///     .mkstream()
+    ///     .entries_read(1);
+    /// let result: RedisResult<String> = con.xgroup_create_options("k1", "g1", opts);
+    /// ```
+    ///
+    #[inline]
+    fn xgroup_create_options<K: ToRedisArgs, G: ToRedisArgs, RV: FromRedisValue>(
+        &mut self,
+        key: K,
+        group: G,
+        options: StreamGroupCreateOptions,
+    ) -> RedisResult<RV> {
+        cmd("XGROUP")
+            .arg("CREATE")
+            .arg(key)
+            .arg(group)
+            .arg(options)
+            .query(self)
+    }
+
+    // XGROUP CREATE <key> <groupname> <id or $> [MKSTREAM] [ENTRIESREAD <entries_read>]
+
+    /// Same as [`xgroup_create_options`], except a group which already
+    /// exists isn't an error. Returns `true` if the group was created.
+    ///
+    /// There's no async version: an error reply ends the async connection.
+    ///
+    /// ```no_run
+    /// use redis_streams::{client_open,Connection,StreamCommands,StreamGroupCreateOptions};
+    /// let client = client_open("redis://127.0.0.1/0").unwrap();
+    /// let mut con = client.get_connection().unwrap();
+    ///
+    /// let opts = StreamGroupCreateOptions::default().mkstream();
+    /// let created = con.xgroup_create_if_missing("k1", "g1", opts).unwrap();
+    /// ```
+    ///
+    /// [`xgroup_create_options`]: #method.xgroup_create_options
+    ///
+    #[inline]
+    fn xgroup_create_if_missing<K: ToRedisArgs, G: ToRedisArgs>(
+        &mut self,
+        key: K,
+        group: G,
+        options: StreamGroupCreateOptions,
+    ) -> RedisResult<bool> {
+        match self.xgroup_create_options::<_, _, ()>(key, group, options) {
+            Ok(()) => Ok(true),
+            Err(e) => match StreamError::from(e) {
+                StreamError::GroupExists(_) => Ok(false),
+                e => Err(e.into()),
+            },
+        }
+    }
+
     // XGROUP SETID <key> <groupname> <id or $>
 
     /// Alter which `id` you want consumers to begin reading from an existing
     /// consumer `group`.
     ///
     #[inline]
-    fn xgroup_setid<K: ToRedisArgs, G: ToRedisArgs, ID: ToRedisArgs, RV: FromRedisValue>(
+    fn xgroup_setid<K: ToRedisArgs, G: ToRedisArgs, ID: Into<ReadFrom>, RV: FromRedisValue>(
         &mut self,
         key: K,
         group: G,
@@ -259,7 +604,36 @@ pub trait StreamCommands: ConnectionLike + Sized {
             .arg("SETID")
             .arg(key)
             .arg(group)
-            .arg(id)
+            .arg(id.into())
+            .query(self)
+    }
+
+    // XGROUP SETID <key> <groupname> <id or $> ENTRIESREAD <entries_read>
+
+    /// An alternate version of `xgroup_setid` which also sets the number
+    /// of entries the group has read, so its `lag` stays correct.
+    /// Requires Redis 7.0 or later.
+    ///
+    #[inline]
+    fn xgroup_setid_entries_read<
+        K: ToRedisArgs,
+        G: ToRedisArgs,
+        ID: Into<ReadFrom>,
+        RV: FromRedisValue,
+    >(
+        &mut self,
+        key: K,
+        group: G,
+        id: ID,
+        entries_read: usize,
+    ) -> RedisResult<RV> {
+        cmd("XGROUP")
+            .arg("SETID")
+            .arg(key)
+            .arg(group)
+            .arg(id.into())
+            .arg("ENTRIESREAD")
+            .arg(entries_read)
             .query(self)
     }
 
@@ -276,6 +650,28 @@ pub trait StreamCommands: ConnectionLike + Sized {
         cmd("XGROUP").arg("DESTROY").arg(key).arg(group).query(self)
     }
 
+    // XGROUP CREATECONSUMER <key> <groupname> <consumername>
+
+    /// Create a `consumer` in an existing consumer `group` for given
+    /// stream `key`, without having to read from it first. Returns 1
+    /// if the consumer was created and 0 if it already existed.
+    /// Requires Redis 6.2 or later.
+    ///
+    #[inline]
+    fn xgroup_createconsumer<K: ToRedisArgs, G: ToRedisArgs, C: ToRedisArgs, RV: FromRedisValue>(
+        &mut self,
+        key: K,
+        group: G,
+        consumer: C,
+    ) -> RedisResult<RV> {
+        cmd("XGROUP")
+            .arg("CREATECONSUMER")
+            .arg(key)
+            .arg(group)
+            .arg(consumer)
+            .query(self)
+    }
+
     // XGROUP DELCONSUMER <key> <groupname> <consumername>
 
     /// This deletes a `consumer` from an existing consumer `group`
@@ -345,6 +741,43 @@ pub trait StreamCommands: ConnectionLike + Sized {
         cmd("XINFO").arg("STREAM").arg(key).query(self)
     }
 
+    // XINFO STREAM <key> FULL
+
+    /// Returns the full state of a stream: its entries, its groups with
+    /// their pending entries list (PEL) and each consumer with its own PEL.
+    /// Take note of the StreamInfoStreamFullReply return type.
+    ///
+    /// By default Redis returns at most 10 entries and 10 PEL entries
+    /// per group and consumer. Use `xinfo_stream_full_count` to change this.
+    ///
+    #[inline]
+    fn xinfo_stream_full<K: ToRedisArgs>(
+        &mut self,
+        key: K,
+    ) -> RedisResult<StreamInfoStreamFullReply> {
+        cmd("XINFO").arg("STREAM").arg(key).arg("FULL").query(self)
+    }
+
+    // XINFO STREAM <key> FULL COUNT <count>
+
+    /// An alternate version of `xinfo_stream_full` which limits the number
+    /// of entries and PEL entries returned. A `count` of 0 returns everything.
+    ///
+    #[inline]
+    fn xinfo_stream_full_count<K: ToRedisArgs, C: ToRedisArgs>(
+        &mut self,
+        key: K,
+        count: C,
+    ) -> RedisResult<StreamInfoStreamFullReply> {
+        cmd("XINFO")
+            .arg("STREAM")
+            .arg(key)
+            .arg("FULL")
+            .arg("COUNT")
+            .arg(count)
+            .query(self)
+    }
+
     // XLEN <key>
     /// Returns the number of messages for a given stream `key`.
     ///
@@ -380,8 +813,8 @@ pub trait StreamCommands: ConnectionLike + Sized {
     /// This XPENDING version returns a list of all messages over the range.
     /// You can use this for paginating pending messages (but without the message HashMap).
     ///
-    /// Start and end follow the same rules `xrange` args. Set start to `-`
-    /// and end to `+` for the entire stream.
+    /// Start and end follow the same rules `xrange` args. Set start to `RangeBound::Min`
+    /// and end to `RangeBound::Max` for the entire stream.
     ///
     /// Take note of the StreamPendingCountReply return type.
     ///
@@ -389,8 +822,8 @@ pub trait StreamCommands: ConnectionLike + Sized {
     fn xpending_count<
         K: ToRedisArgs,
         G: ToRedisArgs,
-        S: ToRedisArgs,
-        E: ToRedisArgs,
+        S: Into<RangeBound>,
+        E: Into<RangeBound>,
         C: ToRedisArgs,
     >(
         &mut self,
@@ -403,8 +836,8 @@ pub trait StreamCommands: ConnectionLike + Sized {
         cmd("XPENDING")
             .arg(key)
             .arg(group)
-            .arg(start)
-            .arg(end)
+            .arg(start.into())
+            .arg(end.into())
             .arg(count)
             .query(self)
     }
@@ -413,8 +846,8 @@ pub trait StreamCommands: ConnectionLike + Sized {
 
     /// An alternate version of `xpending_count` which filters by `consumer` name.
     ///
-    /// Start and end follow the same rules `xrange` args. Set start to `-`
-    /// and end to `+` for the entire stream.
+    /// Start and end follow the same rules `xrange` args. Set start to `RangeBound::Min`
+    /// and end to `RangeBound::Max` for the entire stream.
     ///
     /// Take note of the StreamPendingCountReply return type.
     ///
@@ -422,8 +855,8 @@ pub trait StreamCommands: ConnectionLike + Sized {
     fn xpending_consumer_count<
         K: ToRedisArgs,
         G: ToRedisArgs,
-        S: ToRedisArgs,
-        E: ToRedisArgs,
+        S: Into<RangeBound>,
+        E: Into<RangeBound>,
         C: ToRedisArgs,
         CN: ToRedisArgs,
     >(
@@ -438,31 +871,100 @@ pub trait StreamCommands: ConnectionLike + Sized {
         cmd("XPENDING")
             .arg(key)
             .arg(group)
-            .arg(start)
-            .arg(end)
+            .arg(start.into())
+            .arg(end.into())
             .arg(count)
             .arg(consumer)
             .query(self)
     }
 
+    // XPENDING <key> <group> IDLE <min-idle-time> <start> <stop> <count>
+
+    /// An alternate version of `xpending_count` which only returns messages
+    /// that have been idle for at least `min_idle_time` ms.
+    ///
+    /// Start and end follow the same rules `xrange` args. Set start to `RangeBound::Min`
+    /// and end to `RangeBound::Max` for the entire stream.
+    ///
+    /// The `IDLE` option requires Redis 6.2 or later.
+    ///
+    #[inline]
+    fn xpending_idle_count<
+        K: ToRedisArgs,
+        G: ToRedisArgs,
+        MIT: ToRedisArgs,
+        S: Into<RangeBound>,
+        E: Into<RangeBound>,
+        C: ToRedisArgs,
+    >(
+        &mut self,
+        key: K,
+        group: G,
+        min_idle_time: MIT,
+        start: S,
+        end: E,
+        count: C,
+    ) -> RedisResult<StreamPendingCountReply> {
+        cmd("XPENDING")
+            .arg(key)
+            .arg(group)
+            .arg("IDLE")
+            .arg(min_idle_time)
+            .arg(start.into())
+            .arg(end.into())
+            .arg(count)
+            .query(self)
+    }
+
+    // XPENDING <key> <group> [IDLE <min-idle-time>] <start> <stop> <count> [<consumer>]
+
+    /// The options version of `xpending_count`, which can combine the
+    /// range, count, consumer and `IDLE` filters in one call.
+    ///
+    /// ```no_run
+    /// use redis_streams::{client_open,Connection,RedisResult,StreamCommands,StreamPendingOptions};
+    /// let client = client_open("redis://127.0.0.1/0").unwrap();
+    /// let mut con = client.get_connection().unwrap();
+    ///
+    /// // messages idle for more than 30s for consumer c1
+    /// let opts = StreamPendingOptions::default().idle(30000).consumer("c1");
+    /// let reply = con.xpending_options("k1", "g1", opts).unwrap();
+    /// ```
+    ///
+    /// Take note of the StreamPendingCountReply return type.
+    ///
+    #[inline]
+    fn xpending_options<K: ToRedisArgs, G: ToRedisArgs>(
+        &mut self,
+        key: K,
+        group: G,
+        options: StreamPendingOptions,
+    ) -> RedisResult<StreamPendingCountReply> {
+        cmd("XPENDING").arg(key).arg(group).arg(options).query(self)
+    }
+
     // XRANGE key start end
 
     /// Returns a range of messages in a given stream `key`.
     ///
-    /// Set `start` to `-` to begin at the first message.
-    /// Set `end` to `+` to end the most recent message.
+    /// Set `start` to `RangeBound::Min` to begin at the first message.
+    /// Set `end` to `RangeBound::Max` to end the most recent message.
     /// You can pass message `id` to both `start` and `end`.
     ///
     /// Take note of the StreamRangeReply return type.
     ///
     #[inline]
-    fn xrange<K: ToRedisArgs, S: ToRedisArgs, E: ToRedisArgs>(
+    fn xrange<K: ToRedisArgs, S: Into<RangeBound>, E: Into<RangeBound>>(
         &mut self,
         key: K,
         start: S,
         end: E,
     ) -> RedisResult<StreamRangeReply> {
-        cmd("XRANGE").arg(key).arg(start).arg(end).query(self)
+        cmd("XRANGE")
+            .arg(key)
+            .arg(start.into())
+            .arg(end.into())
+            .query(self)
     }
 
     // XRANGE key - +
@@ -472,15 +974,20 @@ pub trait StreamCommands: ConnectionLike + Sized {
     ///
     #[inline]
     fn xrange_all<K: ToRedisArgs, RV: FromRedisValue>(&mut self, key: K) -> RedisResult<RV> {
-        cmd("XRANGE").arg(key).arg("-").arg("+").query(self)
+        cmd("XRANGE")
+            .arg(key)
+            .arg(RangeBound::Min)
+            .arg(RangeBound::Max)
+            .query(self)
     }
 
     // XRANGE key start end [COUNT <n>]
 
     /// A method for paginating a stream by `key`.
+    /// See `xrange_iter` for following every page.
     ///
     #[inline]
-    fn xrange_count<K: ToRedisArgs, S: ToRedisArgs, E: ToRedisArgs, C: ToRedisArgs>(
+    fn xrange_count<K: ToRedisArgs, S: Into<RangeBound>, E: Into<RangeBound>, C: ToRedisArgs>(
         &mut self,
         key: K,
         start: S,
@@ -489,13 +996,75 @@ pub trait StreamCommands: ConnectionLike + Sized {
     ) -> RedisResult<StreamRangeReply> {
         cmd("XRANGE")
             .arg(key)
-            .arg(start)
-            .arg(end)
+            .arg(start.into())
+            .arg(end.into())
             .arg("COUNT")
             .arg(count)
             .query(self)
     }
 
+    // XRANGE key start end
+
+    /// Same as [`xrange`], except the field names are kept as bytes,
+    /// for streams whose fields aren't valid UTF-8.
+    ///
+    /// ```no_run
+    /// use redis_streams::{client_open,RangeBound,StreamCommands};
+    /// let client = client_open("redis://127.0.0.1/0").unwrap();
+    /// let mut con = client.get_connection().unwrap();
+    ///
+    /// let reply = con.xrange_bytes(&b"k\xff"[..], RangeBound::Min, RangeBound::Max).unwrap();
+    /// for stream_id in reply.ids {
+    ///     println!("{} {:?}", stream_id.id, stream_id.map.get(&b"\xfe"[..]));
+    /// }
+    /// ```
+    ///
+    /// [`xrange`]: #method.xrange
+    ///
+    #[inline]
+    fn xrange_bytes<K: ToRedisArgs, S: Into<RangeBound>, E: Into<RangeBound>>(
+        &mut self,
+        key: K,
+        start: S,
+        end: E,
+    ) -> RedisResult<StreamRangeReply<Vec<u8>>> {
+        cmd("XRANGE")
+            .arg(key)
+            .arg(start.into())
+            .arg(end.into())
+            .query(self)
+    }
+
+    // XRANGE key start end COUNT <page_size> (repeated)
+
+    /// Returns an iterator over all messages between `start` and `end`
+    /// for a given stream `key`, fetching `page_size` messages per call.
+    ///
+    /// Each page after the first starts at an exclusive bound on the last
+    /// `id` returned, which requires Redis 6.2 or later.
+    ///
+    /// ```no_run
+    /// use redis_streams::{client_open,RangeBound,StreamCommands};
+    /// let client = client_open("redis://127.0.0.1/0").unwrap();
+    /// let mut con = client.get_connection().unwrap();
+    ///
+    /// for result in con.xrange_iter("k1", RangeBound::Min, RangeBound::Max, 100) {
+    ///     let stream_id = result.unwrap();
+    ///     println!("{}", stream_id.id);
+    /// }
+    /// ```
+    ///
+    #[inline]
+    fn xrange_iter<K: ToRedisArgs, S: Into<RangeBound>, E: Into<RangeBound>>(
+        &mut self,
+        key: K,
+        start: S,
+        end: E,
+        page_size: usize,
+    ) -> StreamRangeIter<'_, Self> {
+        StreamRangeIter::new(self, key, start.into(), end.into(), page_size, false)
+    }
+
     // XREAD STREAMS key_1 key_2 ... key_N ID_1 ID_2 ... ID_N
 
     /// Read a list of `id`s for each stream `key`.
@@ -504,10 +1073,10 @@ pub trait StreamCommands: ConnectionLike + Sized {
     /// see `xread_options`.
     ///
     #[inline]
-    fn xread<K: ToRedisArgs, ID: ToRedisArgs>(
+    fn xread<K: ToRedisArgs>(
         &mut self,
         keys: &[K],
-        ids: &[ID],
+        ids: &[ReadFrom],
     ) -> RedisResult<StreamReadReply> {
         cmd("XREAD").arg("STREAMS").arg(keys).arg(ids).query(self)
     }
@@ -521,8 +1090,12 @@ pub trait StreamCommands: ConnectionLike + Sized {
 
     /// This method handles setting optional arguments for
     /// `XREAD` or `XREADGROUP` Redis commands.
+    ///
+    /// The `ids` are `ReadFrom`s for `XREAD`, and `GroupReadFrom`s once
+    /// the options have a `group`.
+    ///
     /// ```no_run
-    /// use redis_streams::{client_open,Connection,RedisResult,StreamCommands,StreamReadOptions,StreamReadReply};
+    /// use redis_streams::{client_open,Connection,GroupReadFrom,ReadFrom,RedisResult,StreamCommands,StreamEntryId,StreamReadOptions,StreamReadReply};
     /// let client = client_open("redis://127.0.0.1/0").unwrap();
     /// let mut con = client.get_connection().unwrap();
     ///
@@ -532,36 +1105,174 @@ pub trait StreamCommands: ConnectionLike + Sized {
     /// let opts = StreamReadOptions::default()
     ///     .count(10);
     /// let results: RedisResult<StreamReadReply> =
-    ///     con.xread_options(&["k1"], &["0"], opts);
+    ///     con.xread_options(&["k1"], &[ReadFrom::After(StreamEntryId::MIN)], opts);
     ///
     /// // Read all undelivered messages for a given
     /// // consumer group. Be advised: the consumer group must already
     /// // exist before making this call. Also note: we're passing
-    /// // '>' as the id here, which means all underlivered messages.
+    /// // `GroupReadFrom::Undelivered` ('>') as the id here, which means all underlivered messages.
     ///
     /// let opts = StreamReadOptions::default()
     ///     .group("group-1", "consumer-1");
     /// let results: RedisResult<StreamReadReply> =
-    ///     con.xread_options(&["k1"], &[">"], opts);
+    ///     con.xread_options(&["k1"], &[GroupReadFrom::Undelivered], opts);
     /// ```
     ///
     #[inline]
-    fn xread_options<K: ToRedisArgs, ID: ToRedisArgs>(
+    fn xread_options<K: ToRedisArgs, G: StreamReadMode>(
         &mut self,
         keys: &[K],
-        ids: &[ID],
-        options: StreamReadOptions,
+        ids: &[G::Id],
+        options: StreamReadOptions<G>,
     ) -> RedisResult<StreamReadReply> {
-        cmd(if options.read_only() {
-            "XREAD"
-        } else {
-            "XREADGROUP"
-        })
-        .arg(options)
-        .arg("STREAMS")
-        .arg(keys)
-        .arg(ids)
-        .query(self)
+        cmd(G::COMMAND)
+            .arg(options)
+            .arg("STREAMS")
+            .arg(keys)
+            .arg(ids)
+            .query(self)
+    }
+
+    // XREAD STREAMS key_1 key_2 ... key_N ID_1 ID_2 ... ID_N
+
+    /// Same as [`xread`], except the keys and field names are kept
+    /// as bytes, for streams whose names aren't valid UTF-8.
+    ///
+    /// [`xread`]: #method.xread
+    ///
+    #[inline]
+    fn xread_bytes<K: ToRedisArgs>(
+        &mut self,
+        keys: &[K],
+        ids: &[ReadFrom],
+    ) -> RedisResult<StreamReadBytesReply> {
+        cmd("XREAD").arg("STREAMS").arg(keys).arg(ids).query(self)
+    }
+
+    // XREAD [BLOCK <milliseconds>] [COUNT <count>]
+    //       STREAMS key_1 key_2 ... key_N
+    //       ID_1 ID_2 ... ID_N
+    // XREADGROUP [BLOCK <milliseconds>] [COUNT <count>] [GROUP group-name consumer-name]
+    //       STREAMS key_1 key_2 ... key_N
+    //       ID_1 ID_2 ... ID_N
+
+    /// Same as [`xread_options`], except the keys and field names are
+    /// kept as bytes, for streams whose names aren't valid UTF-8.
+    ///
+    /// [`xread_options`]: #method.xread_options
+    ///
+    #[inline]
+    fn xread_options_bytes<K: ToRedisArgs, G: StreamReadMode>(
+        &mut self,
+        keys: &[K],
+        ids: &[G::Id],
+        options: StreamReadOptions<G>,
+    ) -> RedisResult<StreamReadBytesReply> {
+        cmd(G::COMMAND)
+            .arg(options)
+            .arg("STREAMS")
+            .arg(keys)
+            .arg(ids)
+            .query(self)
+    }
+
+    // XREAD BLOCK <milliseconds> COUNT <count> STREAMS key_1 ... key_N ID_1 ... ID_N (repeated)
+
+    /// Returns an iterator which tails each stream in `keys`, starting after
+    /// the matching position in `ids`. Each call blocks for up to `block` milliseconds
+    /// and reads at most `count` messages per key.
+    ///
+    /// Use `positions` on the iterator to resume from the last message read.
+    ///
+    /// ```no_run
+    /// use redis_streams::{client_open,ReadFrom,StreamCommands};
+    /// let client = client_open("redis://127.0.0.1/0").unwrap();
+    /// let mut con = client.get_connection().unwrap();
+    ///
+    /// let mut tail = con.xread_iter(&["k1", "k2"], &[ReadFrom::New, ReadFrom::New], 1000, 100);
+    /// for result in tail.by_ref().take(10) {
+    ///     let stream_id = result.unwrap();
+    ///     println!("{}", stream_id.id);
+    /// }
+    /// let positions = tail.positions().to_vec();
+    /// ```
+    ///
+    #[inline]
+    fn xread_iter<K: ToRedisArgs>(
+        &mut self,
+        keys: &[K],
+        ids: &[ReadFrom],
+        block: usize,
+        count: usize,
+    ) -> StreamTailIter<'_, Self> {
+        StreamTailIter::new(self, keys, ids, block, count)
+    }
+
+    // XREAD STREAMS key_1 key_2 ... key_N ID_1 ID_2 ... ID_N
+
+    /// Same as [`xread`], except every entry is converted to `T`
+    /// with `FromStreamEntry`. An entry which can't be converted
+    /// fails the whole reply.
+    ///
+    #[cfg_attr(feature = "derive", doc = "```no_run")]
+    #[cfg_attr(not(feature = "derive"), doc = "```ignore")]
+    /// use redis_streams::{client_open,FromStreamEntry,ReadFrom,StreamCommands,StreamEntryId};
+    ///
+    /// #[derive(FromStreamEntry)]
+    /// struct Event {
+    ///     name: String,
+    /// }
+    ///
+    /// let client = client_open("redis://127.0.0.1/0").unwrap();
+    /// let mut con = client.get_connection().unwrap();
+    ///
+    /// let reply = con
+    ///     .xread_typed::<Event, _>(&["k1"], &[ReadFrom::After(StreamEntryId::MIN)])
+    ///     .unwrap();
+    /// for entry in reply.entries {
+    ///     println!("{} {}", entry.id, entry.value.name);
+    /// }
+    /// ```
+    ///
+    /// [`xread`]: #method.xread
+    ///
+    #[inline]
+    fn xread_typed<T: FromStreamEntry, K: ToRedisArgs>(
+        &mut self,
+        keys: &[K],
+        ids: &[ReadFrom],
+    ) -> RedisResult<StreamTypedReply<T>> {
+        cmd("XREAD").arg("STREAMS").arg(keys).arg(ids).query(self)
+    }
+
+    // XREAD [BLOCK <milliseconds>] [COUNT <count>]
+    //       STREAMS key_1 key_2 ... key_N
+    //       ID_1 ID_2 ... ID_N
+    // XREADGROUP [BLOCK <milliseconds>] [COUNT <count>] [GROUP group-name consumer-name]
+    //       STREAMS key_1 key_2 ... key_N
+    //       ID_1 ID_2 ... ID_N
+
+    /// Same as [`xread_options`], except every entry is converted to `T`
+    /// with `FromStreamEntry`.
+    ///
+    /// Be advised: with a `group`, the entries are delivered (and pending)
+    /// even if the conversion fails.
+    ///
+    /// [`xread_options`]: #method.xread_options
+    ///
+    #[inline]
+    fn xread_typed_options<T: FromStreamEntry, K: ToRedisArgs, G: StreamReadMode>(
+        &mut self,
+        keys: &[K],
+        ids: &[G::Id],
+        options: StreamReadOptions<G>,
+    ) -> RedisResult<StreamTypedReply<T>> {
+        cmd(G::COMMAND)
+            .arg(options)
+            .arg("STREAMS")
+            .arg(keys)
+            .arg(ids)
+            .query(self)
     }
 
     // XREVRANGE key end start
@@ -570,13 +1281,17 @@ pub trait StreamCommands: ConnectionLike + Sized {
     /// The same rules apply for `start` and `end` here.
     ///
     #[inline]
-    fn xrevrange<K: ToRedisArgs, E: ToRedisArgs, S: ToRedisArgs>(
+    fn xrevrange<K: ToRedisArgs, E: Into<RangeBound>, S: Into<RangeBound>>(
         &mut self,
         key: K,
         end: E,
         start: S,
     ) -> RedisResult<StreamRangeReply> {
-        cmd("XREVRANGE").arg(key).arg(end).arg(start).query(self)
+        cmd("XREVRANGE")
+            .arg(key)
+            .arg(end.into())
+            .arg(start.into())
+            .query(self)
     }
 
     // XREVRANGE key + -
@@ -585,7 +1300,11 @@ pub trait StreamCommands: ConnectionLike + Sized {
     /// The same rules apply for `start` and `end` here.
     ///
     fn xrevrange_all<K: ToRedisArgs>(&mut self, key: K) -> RedisResult<StreamRangeReply> {
-        cmd("XREVRANGE").arg(key).arg("+").arg("-").query(self)
+        cmd("XREVRANGE")
+            .arg(key)
+            .arg(RangeBound::Max)
+            .arg(RangeBound::Min)
+            .query(self)
     }
 
     // XREVRANGE key end start [COUNT <n>]
@@ -594,7 +1313,7 @@ pub trait StreamCommands: ConnectionLike + Sized {
     /// The same rules apply for `start` and `end` here.
     ///
     #[inline]
-    fn xrevrange_count<K: ToRedisArgs, E: ToRedisArgs, S: ToRedisArgs, C: ToRedisArgs>(
+    fn xrevrange_count<K: ToRedisArgs, E: Into<RangeBound>, S: Into<RangeBound>, C: ToRedisArgs>(
         &mut self,
         key: K,
         end: E,
@@ -603,24 +1322,98 @@ pub trait StreamCommands: ConnectionLike + Sized {
     ) -> RedisResult<StreamRangeReply> {
         cmd("XREVRANGE")
             .arg(key)
-            .arg(end)
-            .arg(start)
+            .arg(end.into())
+            .arg(start.into())
             .arg("COUNT")
             .arg(count)
             .query(self)
     }
 
-    // XTRIM <key> MAXLEN [~|=] <count>  (Same as XADD MAXLEN option)
+    // XREVRANGE key end start COUNT <page_size> (repeated)
+
+    /// This is the reverse version of `xrange_iter`.
+    /// Messages are returned from `end` down to `start`.
+    ///
+    #[inline]
+    fn xrevrange_iter<K: ToRedisArgs, E: Into<RangeBound>, S: Into<RangeBound>>(
+        &mut self,
+        key: K,
+        end: E,
+        start: S,
+        page_size: usize,
+    ) -> StreamRangeIter<'_, Self> {
+        StreamRangeIter::new(self, key, start.into(), end.into(), page_size, true)
+    }
+
+    // XSETID <key> <last-id>
 
-    /// Trim a stream `key` to a MAXLEN count.
+    /// Set the last generated `id` of a stream `key`, for example when
+    /// restoring a stream from a backup. The `id` can't be smaller than
+    /// the largest `id` in the stream.
+    ///
+    #[inline]
+    fn xsetid<K: ToRedisArgs, RV: FromRedisValue>(
+        &mut self,
+        key: K,
+        id: StreamEntryId,
+    ) -> RedisResult<RV> {
+        cmd("XSETID").arg(key).arg(id).query(self)
+    }
+
+    // XSETID <key> <last-id> [ENTRIESADDED <entries_added>] [MAXDELETEDID <max_deleted_id>]
+
+    /// The options version of `xsetid`, which also restores the
+    /// `entries-added` counter and the max deleted `id` of the stream.
+    /// These options require Redis 7.0 or later.
+    ///
+    /// ```no_run
+    /// use redis_streams::{client_open,Connection,RedisResult,StreamCommands,StreamEntryId,StreamSetIdOptions};
+    /// let client = client_open("redis://127.0.0.1/0").unwrap();
+    /// let mut con = client.get_connection().unwrap();
+    ///
+    /// let opts = StreamSetIdOptions::default()
+    ///     .entries_added(10)
+    ///     .max_deleted_id(StreamEntryId::new(1000, 0));
+    /// let result: RedisResult<String> =
+    ///     con.xsetid_options("k1", StreamEntryId::new(2000, 0), opts);
+    /// ```
+    ///
+    #[inline]
+    fn xsetid_options<K: ToRedisArgs, RV: FromRedisValue>(
+        &mut self,
+        key: K,
+        id: StreamEntryId,
+        options: StreamSetIdOptions,
+    ) -> RedisResult<RV> {
+        cmd("XSETID").arg(key).arg(id).arg(options).query(self)
+    }
+
+    // XTRIM <key> MAXLEN|MINID [~|=] <threshold> [LIMIT <count>]  (Same as XADD trim option)
+
+    /// Trim a stream `key` to a MAXLEN count with a `StreamMaxlen`,
+    /// or by MAXLEN or MINID with a `StreamTrimStrategy`.
+    ///
+    /// ```no_run
+    /// use redis_streams::{client_open,RedisResult,StreamCommands,StreamTrimStrategy,StreamTrimmingMode};
+    /// use std::time::Duration;
+    /// let client = client_open("redis://127.0.0.1/0").unwrap();
+    /// let mut con = client.get_connection().unwrap();
+    ///
+    /// // Evict messages older than an hour,
+    /// // removing at most 1000 per call.
+    ///
+    /// let mode = StreamTrimmingMode::ApproxLimit(1000);
+    /// let trim = StreamTrimStrategy::minid_ago(mode, Duration::from_secs(3600));
+    /// let trimmed: RedisResult<usize> = con.xtrim("k1", trim);
+    /// ```
     ///
     #[inline]
-    fn xtrim<K: ToRedisArgs, RV: FromRedisValue>(
+    fn xtrim<K: ToRedisArgs, T: Into<StreamTrimStrategy>, RV: FromRedisValue>(
         &mut self,
         key: K,
-        maxlen: StreamMaxlen,
+        trim: T,
     ) -> RedisResult<RV> {
-        cmd("XTRIM").arg(key).arg(maxlen).query(self)
+        cmd("XTRIM").arg(key).arg(trim.into()).query(self)
     }
 }
 
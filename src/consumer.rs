@@ -0,0 +1,200 @@
+use crate::commands::StreamCommands;
+use crate::types::{
+    GroupReadFrom, ReadFrom, StreamEntryId, StreamGroupCreateOptions, StreamId, StreamReadOptions,
+    StreamReadReply,
+};
+
+use redis::{ConnectionLike, RedisResult, ToRedisArgs};
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// Handle used to ask a running [`GroupConsumer`] to stop.
+///
+/// The consumer checks the flag between entries and after every
+/// blocking read, so it stops within one `block` timeout.
+///
+/// [`GroupConsumer`]: ./struct.GroupConsumer.html
+///
+#[derive(Default, Debug, Clone)]
+pub struct ShutdownHandle(Arc<AtomicBool>);
+
+impl ShutdownHandle {
+    /// Ask the consumer to stop.
+    pub fn shutdown(&self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+
+    /// Whether a shutdown was requested.
+    pub fn is_shutdown(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
+/// A consumer group worker which runs the `XREADGROUP`, process,
+/// `XACK` loop on a single stream.
+///
+/// On [`run`], the group is created with `MKSTREAM` if it doesn't exist.
+/// The consumer then goes through its own pending entries (`0`) before
+/// reading new ones (`>`). Every entry is passed to the handler and
+/// acked once the handler returns `Ok`. Entries for which the handler
+/// returns `Err` are passed to the error handler along with the error.
+/// They stay pending and are retried the next time the consumer starts
+/// (or can be claimed by another consumer).
+///
+/// Pending entries which were deleted from the stream come back without
+/// fields. They're acked without being passed to the handler.
+///
+/// ```no_run
+/// use redis_streams::{client_open, GroupConsumer};
+/// let client = client_open("redis://127.0.0.1/0").unwrap();
+/// let mut con = client.get_connection().unwrap();
+/// let consumer = GroupConsumer::new("k1", "g1", "c1").block(1000).count(10);
+/// let shutdown = consumer.shutdown_handle();
+/// consumer
+///     .run(
+///         &mut con,
+///         |entry| {
+///             println!("{:?}", entry.id);
+///             shutdown.shutdown();
+///             Ok::<(), String>(())
+///         },
+///         |entry, e| eprintln!("{:?} failed: {}", entry.id, e),
+///     )
+///     .unwrap();
+/// ```
+///
+/// [`run`]: #method.run
+///
+#[derive(Debug)]
+pub struct GroupConsumer {
+    key: Vec<Vec<u8>>,
+    group: Vec<Vec<u8>>,
+    consumer: Vec<Vec<u8>>,
+    start: ReadFrom,
+    block: usize,
+    count: usize,
+    shutdown: ShutdownHandle,
+}
+
+impl GroupConsumer {
+    /// Create a consumer named `consumer` in `group` on the stream `key`.
+    pub fn new<K: ToRedisArgs, G: ToRedisArgs, C: ToRedisArgs>(
+        key: K,
+        group: G,
+        consumer: C,
+    ) -> Self {
+        GroupConsumer {
+            key: ToRedisArgs::to_redis_args(&key),
+            group: ToRedisArgs::to_redis_args(&group),
+            consumer: ToRedisArgs::to_redis_args(&consumer),
+            start: ReadFrom::After(StreamEntryId::MIN),
+            block: 5000,
+            count: 100,
+            shutdown: ShutdownHandle::default(),
+        }
+    }
+
+    /// The id the group starts from when it has to be created.
+    /// Defaults to the beginning of the stream.
+    pub fn start(mut self, start: ReadFrom) -> Self {
+        self.start = start;
+        self
+    }
+
+    /// How long to block, in ms, waiting for new entries.
+    /// This is also the longest it takes to notice a shutdown.
+    pub fn block(mut self, ms: usize) -> Self {
+        self.block = ms;
+        self
+    }
+
+    /// The max number of entries read at once.
+    pub fn count(mut self, n: usize) -> Self {
+        self.count = n.max(1);
+        self
+    }
+
+    /// A handle which stops the consumer from another thread
+    /// (or from the handler itself).
+    pub fn shutdown_handle(&self) -> ShutdownHandle {
+        self.shutdown.clone()
+    }
+
+    /// Run the consumer until a shutdown is requested or a command fails.
+    ///
+    /// Processed entries are acked in one `XACK` per read, including
+    /// when stopping in the middle of a batch. Unprocessed entries of
+    /// that batch stay pending for the next start.
+    pub fn run<C, F, E, H>(&self, con: &mut C, mut handler: F, mut on_error: H) -> RedisResult<()>
+    where
+        C: ConnectionLike,
+        F: FnMut(&StreamId) -> Result<(), E>,
+        H: FnMut(&StreamId, E),
+    {
+        self.create_group(con)?;
+
+        // pending entries are read from an explicit id, which
+        // moves past the ones the handler fails so we don't spin on them.
+        let mut pending = Some(StreamEntryId::MIN);
+
+        while !self.shutdown.is_shutdown() {
+            let position = match pending {
+                Some(id) => GroupReadFrom::After(id),
+                None => GroupReadFrom::Undelivered,
+            };
+            let reply = self.read(con, position)?;
+            let ids = reply
+                .keys
+                .into_iter()
+                .next()
+                .map(|k| k.ids)
+                .unwrap_or_default();
+
+            if pending.is_some() {
+                pending = ids.last().map(|last| last.id);
+            }
+
+            let mut acks = Vec::new();
+            for entry in &ids {
+                if self.shutdown.is_shutdown() {
+                    break;
+                }
+                // deleted from the stream while pending,
+                // so there's nothing left to process
+                if entry.map.is_empty() {
+                    acks.push(entry.id);
+                    continue;
+                }
+                match handler(entry) {
+                    Ok(()) => acks.push(entry.id),
+                    Err(e) => on_error(entry, e),
+                }
+            }
+            if !acks.is_empty() {
+                let _: usize = con.xack(&self.key[..], &self.group[..], &acks)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn create_group<C: ConnectionLike>(&self, con: &mut C) -> RedisResult<()> {
+        let options = StreamGroupCreateOptions::default()
+            .start(self.start)
+            .mkstream();
+        con.xgroup_create_if_missing(&self.key[..], &self.group[..], options)?;
+        Ok(())
+    }
+
+    fn read<C: ConnectionLike>(
+        &self,
+        con: &mut C,
+        position: GroupReadFrom,
+    ) -> RedisResult<StreamReadReply> {
+        let options = StreamReadOptions::default()
+            .group(&self.group[..], &self.consumer[..])
+            .block(self.block)
+            .count(self.count);
+        con.xread_options(&[&self.key[..]], &[position], options)
+    }
+}
@@ -0,0 +1,184 @@
+use crate::commands::StreamCommands;
+use crate::types::{AddId, RangeBound, StreamEntryId, StreamId, StreamPendingId};
+
+use redis::{from_redis_value, ConnectionLike, RedisResult, ToRedisArgs};
+
+/// Moves poison messages out of a consumer group and into a
+/// dead-letter stream.
+///
+/// Pending entries are paged through with [`xpending_count`]. The ones
+/// delivered more than `max_deliveries` times are claimed with
+/// [`xclaim`], added to the dead-letter stream and then acked on the
+/// source stream.
+///
+/// Each dead-letter entry holds the fields of the original entry plus
+/// the `*_FIELD` fields below. If the process stops between adding and
+/// acking, the same entry can end up twice in the dead-letter stream.
+///
+/// ```no_run
+/// use redis_streams::{client_open, DeadLetterPolicy};
+/// let client = client_open("redis://127.0.0.1/0").unwrap();
+/// let mut con = client.get_connection().unwrap();
+/// let policy = DeadLetterPolicy::new("k1", "g1", "reaper", "k1:dlq", 5)
+///     .min_idle_time(30000)
+///     .reason("handler failed 5 times");
+/// let moved = policy.run(&mut con).unwrap();
+/// ```
+///
+/// [`xpending_count`]: ./trait.StreamCommands.html#method.xpending_count
+/// [`xclaim`]: ./trait.StreamCommands.html#method.xclaim
+///
+#[derive(Debug, Clone)]
+pub struct DeadLetterPolicy {
+    key: Vec<Vec<u8>>,
+    group: Vec<Vec<u8>>,
+    consumer: Vec<Vec<u8>>,
+    dlq_key: Vec<Vec<u8>>,
+    max_deliveries: usize,
+    min_idle_time: usize,
+    count: usize,
+    reason: String,
+}
+
+impl DeadLetterPolicy {
+    /// Field holding the `id` of the entry in the source stream.
+    pub const ID_FIELD: &'static str = "dlq-original-id";
+    /// Field holding the source stream `key`.
+    pub const KEY_FIELD: &'static str = "dlq-source-key";
+    /// Field holding the consumer `group` the entry was pending in.
+    pub const GROUP_FIELD: &'static str = "dlq-group";
+    /// Field holding the failure reason.
+    pub const REASON_FIELD: &'static str = "dlq-reason";
+    /// Field holding the number of times the entry was delivered.
+    pub const DELIVERIES_FIELD: &'static str = "dlq-deliveries";
+
+    /// Create a policy for `group` on the stream `key`, which moves
+    /// entries delivered more than `max_deliveries` times to `dlq_key`.
+    /// Entries are claimed by `consumer` before being moved.
+    pub fn new<K: ToRedisArgs, G: ToRedisArgs, C: ToRedisArgs, D: ToRedisArgs>(
+        key: K,
+        group: G,
+        consumer: C,
+        dlq_key: D,
+        max_deliveries: usize,
+    ) -> Self {
+        DeadLetterPolicy {
+            key: ToRedisArgs::to_redis_args(&key),
+            group: ToRedisArgs::to_redis_args(&group),
+            consumer: ToRedisArgs::to_redis_args(&consumer),
+            dlq_key: ToRedisArgs::to_redis_args(&dlq_key),
+            max_deliveries,
+            min_idle_time: 0,
+            count: 100,
+            reason: "max deliveries exceeded".to_string(),
+        }
+    }
+
+    /// Only move entries which have been idle for at least `ms`,
+    /// so entries still being worked on aren't taken away.
+    pub fn min_idle_time(mut self, ms: usize) -> Self {
+        self.min_idle_time = ms;
+        self
+    }
+
+    /// The number of pending entries fetched per `XPENDING` call.
+    pub fn count(mut self, n: usize) -> Self {
+        self.count = n.max(1);
+        self
+    }
+
+    /// The failure reason stored with every dead-letter entry.
+    pub fn reason(mut self, reason: &str) -> Self {
+        self.reason = reason.to_string();
+        self
+    }
+
+    /// Check the whole pending entries list once and move the entries
+    /// over the limit. Returns the source ids of the moved entries.
+    pub fn run<C: ConnectionLike>(&self, con: &mut C) -> RedisResult<Vec<StreamEntryId>> {
+        let mut moved = Vec::new();
+        let mut start = RangeBound::Min;
+        loop {
+            let reply = con.xpending_count(
+                &self.key[..],
+                &self.group[..],
+                start,
+                RangeBound::Max,
+                self.count,
+            )?;
+            let done = reply.ids.len() < self.count;
+            if let Some(last) = reply.ids.last() {
+                start = RangeBound::Exclusive(last.id);
+            }
+
+            let poisoned: Vec<&StreamPendingId> = reply
+                .ids
+                .iter()
+                .filter(|p| p.times_delivered > self.max_deliveries)
+                .collect();
+            if !poisoned.is_empty() {
+                moved.extend(self.move_entries(con, &poisoned)?);
+            }
+            if done {
+                return Ok(moved);
+            }
+        }
+    }
+
+    fn move_entries<C: ConnectionLike>(
+        &self,
+        con: &mut C,
+        pending: &[&StreamPendingId],
+    ) -> RedisResult<Vec<StreamEntryId>> {
+        let ids: Vec<StreamEntryId> = pending.iter().map(|p| p.id).collect();
+
+        // entries claimed in between by someone else
+        // (or deleted from the stream) aren't returned
+        let claimed = con.xclaim(
+            &self.key[..],
+            &self.group[..],
+            &self.consumer[..],
+            self.min_idle_time,
+            &ids,
+        )?;
+
+        let mut acks = Vec::new();
+        for entry in &claimed.ids {
+            let deliveries = pending
+                .iter()
+                .find(|p| p.id == entry.id)
+                .map(|p| p.times_delivered + 1)
+                .unwrap_or_default();
+            let fields = self.fields(entry, deliveries)?;
+            let _: StreamEntryId = con.xadd(&self.dlq_key[..], AddId::Auto, &fields)?;
+            acks.push(entry.id);
+        }
+        if !acks.is_empty() {
+            let _: usize = con.xack(&self.key[..], &self.group[..], &acks)?;
+        }
+        Ok(acks)
+    }
+
+    fn fields(&self, entry: &StreamId, deliveries: usize) -> RedisResult<Vec<(String, Vec<u8>)>> {
+        let mut fields = Vec::with_capacity(entry.map.len() + 5);
+        for (field, value) in &entry.map {
+            fields.push((field.to_owned(), from_redis_value(value)?));
+        }
+        fields.push((
+            Self::ID_FIELD.to_string(),
+            entry.id.to_string().into_bytes(),
+        ));
+        // the key and group are each sent as a single arg
+        fields.push((Self::KEY_FIELD.to_string(), self.key.concat()));
+        fields.push((Self::GROUP_FIELD.to_string(), self.group.concat()));
+        fields.push((
+            Self::REASON_FIELD.to_string(),
+            self.reason.clone().into_bytes(),
+        ));
+        fields.push((
+            Self::DELIVERIES_FIELD.to_string(),
+            deliveries.to_string().into_bytes(),
+        ));
+        Ok(fields)
+    }
+}
@@ -0,0 +1,119 @@
+use crate::types::StreamId;
+
+use redis::{from_redis_value, FromRedisValue, RedisError, RedisResult, ToRedisArgs};
+
+/// Build a value from the fields of a stream entry.
+///
+/// Usually derived with `#[derive(FromStreamEntry)]` (behind the `derive`
+/// feature). Each struct field is read from the entry field of the same
+/// name and converted with `FromRedisValue`. These attributes are supported:
+///
+/// - `#[stream(rename = "name")]` reads the entry field `name` instead.
+/// - `#[stream(default)]` uses `Default::default()` if the field is missing.
+/// - `#[stream(optional)]` on an `Option<T>` field gives `None` if the field is missing.
+///
+/// Any other missing field is an error.
+///
+#[cfg_attr(feature = "derive", doc = "```")]
+#[cfg_attr(not(feature = "derive"), doc = "```ignore")]
+/// use redis_streams::{FromStreamEntry, ToStreamEntry};
+///
+/// #[derive(FromStreamEntry, ToStreamEntry)]
+/// struct Event {
+///     #[stream(rename = "type")]
+///     kind: String,
+///     #[stream(default)]
+///     retries: u32,
+///     #[stream(optional)]
+///     user: Option<String>,
+/// }
+/// ```
+///
+pub trait FromStreamEntry: Sized {
+    fn from_stream_entry<F: AsRef<[u8]>>(entry: &StreamId<F>) -> RedisResult<Self>;
+}
+
+/// Turn a value into the field/values of a stream entry.
+///
+/// Usually derived with `#[derive(ToStreamEntry)]`, using the same
+/// attributes as [`FromStreamEntry`]. Fields marked `optional` aren't
+/// written when `None`. The fields can be passed to [`xadd`] as is.
+///
+/// Fails if a value isn't a single redis argument, since an entry value
+/// is a single bulk string. That's the case for a `Vec<String>`, or for
+/// an `Option` set to `None` unless the field is marked `optional`.
+///
+/// [`FromStreamEntry`]: ./trait.FromStreamEntry.html
+/// [`xadd`]: ./trait.StreamCommands.html#method.xadd
+///
+pub trait ToStreamEntry {
+    fn to_stream_entry(&self) -> RedisResult<Vec<(String, Vec<u8>)>>;
+}
+
+/// Helpers called by the code generated with `redis_streams_derive`.
+#[doc(hidden)]
+pub mod derive {
+    use super::*;
+
+    pub fn required<F: AsRef<[u8]>, T: FromRedisValue>(
+        entry: &StreamId<F>,
+        field: &str,
+    ) -> RedisResult<T> {
+        match entry.map.get(field) {
+            Some(value) => convert(value, field),
+            None => Err(RedisError::from((
+                redis::ErrorKind::TypeError,
+                "Stream entry field is missing",
+                field.to_string(),
+            ))),
+        }
+    }
+
+    pub fn or_default<F: AsRef<[u8]>, T: FromRedisValue + Default>(
+        entry: &StreamId<F>,
+        field: &str,
+    ) -> RedisResult<T> {
+        match entry.map.get(field) {
+            Some(value) => convert(value, field),
+            None => Ok(T::default()),
+        }
+    }
+
+    pub fn optional<F: AsRef<[u8]>, T: FromRedisValue>(
+        entry: &StreamId<F>,
+        field: &str,
+    ) -> RedisResult<Option<T>> {
+        match entry.map.get(field) {
+            Some(value) => convert(value, field).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    pub fn push<T: ToRedisArgs>(
+        fields: &mut Vec<(String, Vec<u8>)>,
+        field: &str,
+        value: &T,
+    ) -> RedisResult<()> {
+        // entry values are a single bulk string
+        let mut args = value.to_redis_args();
+        if args.len() != 1 {
+            return Err(RedisError::from((
+                redis::ErrorKind::TypeError,
+                "Stream entry field isn't a single value",
+                field.to_string(),
+            )));
+        }
+        fields.push((field.to_string(), args.remove(0)));
+        Ok(())
+    }
+
+    fn convert<T: FromRedisValue>(value: &redis::Value, field: &str) -> RedisResult<T> {
+        from_redis_value(value).map_err(|_| {
+            RedisError::from((
+                redis::ErrorKind::TypeError,
+                "Stream entry field has an incompatible value",
+                field.to_string(),
+            ))
+        })
+    }
+}
@@ -0,0 +1,106 @@
+use redis::RedisError;
+
+use std::fmt;
+
+/// Stream specific server errors, classified from a `RedisError`.
+///
+/// Use this to tell expected failures apart without matching on error
+/// strings. Every variant keeps the original error, and converts back
+/// into it. Errors which aren't stream specific are kept as `Other`.
+///
+/// ```no_run
+/// use redis_streams::{client_open,ReadFrom,RedisResult,StreamCommands,StreamError};
+/// let client = client_open("redis://127.0.0.1/0").unwrap();
+/// let mut con = client.get_connection().unwrap();
+///
+/// let result: RedisResult<String> = con.xgroup_create("k1", "g1", ReadFrom::New);
+/// match result.map_err(StreamError::from) {
+///     Ok(_) | Err(StreamError::GroupExists(_)) => {}
+///     Err(StreamError::NoGroup(_)) => println!("k1 doesn't exist"),
+///     Err(e) => panic!("{}", e),
+/// }
+/// ```
+///
+#[derive(Debug)]
+pub enum StreamError {
+    /// `BUSYGROUP`: the consumer group already exists.
+    GroupExists(RedisError),
+    /// `NOGROUP`: the consumer group or the stream doesn't exist.
+    NoGroup(RedisError),
+    /// The id is equal or smaller than the last id of the stream
+    /// (or is `0-0`), as returned by `XADD` and `XSETID`.
+    IdTooSmall(RedisError),
+    /// `WRONGTYPE`: the key holds something other than a stream.
+    WrongType(RedisError),
+    /// The id isn't a valid stream id.
+    InvalidId(RedisError),
+    /// Any other error.
+    Other(RedisError),
+}
+
+impl From<RedisError> for StreamError {
+    fn from(err: RedisError) -> Self {
+        match err.extension_error_code() {
+            Some("BUSYGROUP") => return StreamError::GroupExists(err),
+            Some("NOGROUP") => return StreamError::NoGroup(err),
+            Some("WRONGTYPE") => return StreamError::WrongType(err),
+            _ => {}
+        }
+        if err.kind() != redis::ErrorKind::ResponseError {
+            return StreamError::Other(err);
+        }
+
+        // plain `ERR` replies only differ by their message
+        let message = err.to_string();
+        if message.contains("equal or smaller than the target stream top item")
+            || message.contains("is smaller than the target stream top item")
+            || message.contains("must be greater than 0-0")
+        {
+            StreamError::IdTooSmall(err)
+        } else if message.contains("Invalid stream ID") {
+            StreamError::InvalidId(err)
+        } else if message.contains("requires the key to exist") {
+            StreamError::NoGroup(err)
+        } else {
+            StreamError::Other(err)
+        }
+    }
+}
+
+impl From<StreamError> for RedisError {
+    fn from(err: StreamError) -> Self {
+        match err {
+            StreamError::GroupExists(err)
+            | StreamError::NoGroup(err)
+            | StreamError::IdTooSmall(err)
+            | StreamError::WrongType(err)
+            | StreamError::InvalidId(err)
+            | StreamError::Other(err) => err,
+        }
+    }
+}
+
+impl StreamError {
+    fn redis_error(&self) -> &RedisError {
+        match *self {
+            StreamError::GroupExists(ref err)
+            | StreamError::NoGroup(ref err)
+            | StreamError::IdTooSmall(ref err)
+            | StreamError::WrongType(ref err)
+            | StreamError::InvalidId(ref err)
+            | StreamError::Other(ref err) => err,
+        }
+    }
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.redis_error().fmt(f)
+    }
+}
+
+impl std::error::Error for StreamError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(self.redis_error())
+    }
+}
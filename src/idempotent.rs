@@ -0,0 +1,104 @@
+use crate::commands::StreamCommands;
+use crate::types::StreamEntryId;
+
+use redis::{ConnectionLike, RedisResult, ToRedisArgs};
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Publishes entries at most once, with [`xadd_idempotent`].
+///
+/// Every entry gets the next sequence number of this producer. Keep the
+/// number returned by [`next_seq`] with the entry and publish it again
+/// with the same number when a call fails or times out: the server skips
+/// the repeat and returns the id of the original entry.
+///
+/// Sequence numbers start at 1 for every new producer, so give each run
+/// its own `producer_id`, or resume with [`start_seq`]. Otherwise new
+/// entries are taken as repeats of the entries of the last run.
+///
+/// ```no_run
+/// use redis_streams::{client_open,IdempotentProducer};
+/// let client = client_open("redis://127.0.0.1/0").unwrap();
+/// let mut con = client.get_connection().unwrap();
+///
+/// let producer = IdempotentProducer::new("billing-7f3a").ttl(3600000);
+/// let seq = producer.next_seq();
+/// let id = match producer.publish(&mut con, "k1", seq, &[("f", "v")]) {
+///     Ok(id) => id,
+///     // same seq, so this can't add the entry twice
+///     Err(_) => producer.publish(&mut con, "k1", seq, &[("f", "v")]).unwrap(),
+/// };
+/// ```
+///
+/// [`xadd_idempotent`]: ./trait.StreamCommands.html#method.xadd_idempotent
+/// [`next_seq`]: #method.next_seq
+/// [`start_seq`]: #method.start_seq
+///
+#[derive(Debug)]
+pub struct IdempotentProducer {
+    producer_id: String,
+    seq: AtomicU64,
+    ttl: usize,
+}
+
+impl IdempotentProducer {
+    /// Field holding the producer id of an entry.
+    pub const PRODUCER_ID_FIELD: &'static str = "producer-id";
+    /// Field holding the sequence number of an entry.
+    pub const SEQ_FIELD: &'static str = "producer-seq";
+
+    /// Create a producer with a unique `producer_id`.
+    pub fn new(producer_id: &str) -> Self {
+        IdempotentProducer {
+            producer_id: producer_id.to_string(),
+            seq: AtomicU64::new(1),
+            ttl: 86_400_000,
+        }
+    }
+
+    /// How long, in ms, a repeat is recognized for. Defaults to a day.
+    /// A ttl of 0 makes every `publish` fail.
+    pub fn ttl(mut self, ms: usize) -> Self {
+        self.ttl = ms;
+        self
+    }
+
+    /// The sequence number of the next entry.
+    pub fn start_seq(self, seq: u64) -> Self {
+        self.seq.store(seq, Ordering::SeqCst);
+        self
+    }
+
+    /// The id attached to every entry.
+    pub fn producer_id(&self) -> &str {
+        &self.producer_id
+    }
+
+    /// Take the sequence number for a new entry.
+    pub fn next_seq(&self) -> u64 {
+        self.seq.fetch_add(1, Ordering::SeqCst)
+    }
+
+    /// Add the entry numbered `seq` to `key`, unless it was already added.
+    pub fn publish<C: ConnectionLike, K: ToRedisArgs, F: ToRedisArgs, V: ToRedisArgs>(
+        &self,
+        con: &mut C,
+        key: K,
+        seq: u64,
+        items: &[(F, V)],
+    ) -> RedisResult<StreamEntryId> {
+        con.xadd_idempotent(key, &self.producer_id[..], seq, self.ttl, items)
+    }
+}
+
+/// `<key>:dedup:<producer_id>:<seq>`. It hashes to the same slot as `key`
+/// only when `key` contains a `{tag}`, since the whole key is hashed otherwise.
+pub(crate) fn dedup_key(key: &[u8], producer_id: &[u8], seq: u64) -> Vec<u8> {
+    let mut dedup = Vec::with_capacity(key.len() + producer_id.len() + 28);
+    dedup.extend_from_slice(key);
+    dedup.extend_from_slice(b":dedup:");
+    dedup.extend_from_slice(producer_id);
+    dedup.push(b':');
+    dedup.extend_from_slice(seq.to_string().as_bytes());
+    dedup
+}
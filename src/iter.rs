@@ -0,0 +1,184 @@
+use crate::commands::StreamCommands;
+use crate::types::{RangeBound, StreamAutoClaimOptions, StreamEntryId, StreamId};
+
+use redis::{ConnectionLike, RedisResult, ToRedisArgs};
+
+use std::vec::IntoIter;
+
+/// Iterator returned by [`xrange_iter`] and [`xrevrange_iter`].
+///
+/// Pages are fetched with `COUNT <page_size>` and the next page starts
+/// from an exclusive bound on the last `id` seen, so only one page
+/// is held in memory at a time.
+///
+/// [`xrange_iter`]: ./trait.StreamCommands.html#method.xrange_iter
+/// [`xrevrange_iter`]: ./trait.StreamCommands.html#method.xrevrange_iter
+///
+pub struct StreamRangeIter<'a, C: ConnectionLike> {
+    con: &'a mut C,
+    key: Vec<Vec<u8>>,
+    start: RangeBound,
+    end: RangeBound,
+    page_size: usize,
+    reverse: bool,
+    page: IntoIter<StreamId>,
+    done: bool,
+}
+
+impl<'a, C: ConnectionLike> StreamRangeIter<'a, C> {
+    pub(crate) fn new<K: ToRedisArgs>(
+        con: &'a mut C,
+        key: K,
+        start: RangeBound,
+        end: RangeBound,
+        page_size: usize,
+        reverse: bool,
+    ) -> Self {
+        StreamRangeIter {
+            con,
+            key: ToRedisArgs::to_redis_args(&key),
+            start,
+            end,
+            page_size: page_size.max(1),
+            reverse,
+            page: Vec::new().into_iter(),
+            done: false,
+        }
+    }
+
+    fn fetch(&mut self) -> RedisResult<Vec<StreamId>> {
+        let reply = if self.reverse {
+            self.con
+                .xrevrange_count(&self.key[..], self.end, self.start, self.page_size)?
+        } else {
+            self.con
+                .xrange_count(&self.key[..], self.start, self.end, self.page_size)?
+        };
+        let ids = reply.ids;
+
+        if ids.len() < self.page_size {
+            self.done = true;
+        }
+        if let Some(last) = ids.last() {
+            if self.reverse {
+                self.end = RangeBound::Exclusive(last.id);
+            } else {
+                self.start = RangeBound::Exclusive(last.id);
+            }
+        }
+        Ok(ids)
+    }
+}
+
+impl<'a, C: ConnectionLike> Iterator for StreamRangeIter<'a, C> {
+    type Item = RedisResult<StreamId>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(id) = self.page.next() {
+            return Some(Ok(id));
+        }
+        if self.done {
+            return None;
+        }
+        match self.fetch() {
+            Ok(ids) => {
+                self.page = ids.into_iter();
+                self.page.next().map(Ok)
+            }
+            Err(e) => {
+                self.done = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+/// Iterator returned by [`xautoclaim_iter`].
+///
+/// Each call to `XAUTOCLAIM` continues from the cursor returned by the
+/// previous one until the server returns a `0-0` cursor.
+///
+/// [`xautoclaim_iter`]: ./trait.StreamCommands.html#method.xautoclaim_iter
+///
+pub struct StreamAutoClaimIter<'a, C: ConnectionLike> {
+    con: &'a mut C,
+    key: Vec<Vec<u8>>,
+    group: Vec<Vec<u8>>,
+    consumer: Vec<Vec<u8>>,
+    min_idle_time: Vec<Vec<u8>>,
+    options: StreamAutoClaimOptions,
+    cursor: StreamEntryId,
+    deleted_ids: Vec<StreamEntryId>,
+    page: IntoIter<StreamId>,
+    done: bool,
+}
+
+impl<'a, C: ConnectionLike> StreamAutoClaimIter<'a, C> {
+    pub(crate) fn new<K: ToRedisArgs, G: ToRedisArgs, CN: ToRedisArgs, MIT: ToRedisArgs>(
+        con: &'a mut C,
+        key: K,
+        group: G,
+        consumer: CN,
+        min_idle_time: MIT,
+        options: StreamAutoClaimOptions,
+    ) -> Self {
+        StreamAutoClaimIter {
+            con,
+            key: ToRedisArgs::to_redis_args(&key),
+            group: ToRedisArgs::to_redis_args(&group),
+            consumer: ToRedisArgs::to_redis_args(&consumer),
+            min_idle_time: ToRedisArgs::to_redis_args(&min_idle_time),
+            options,
+            cursor: StreamEntryId::MIN,
+            deleted_ids: Vec::new(),
+            page: Vec::new().into_iter(),
+            done: false,
+        }
+    }
+
+    /// Pending ids found to be deleted from the stream so far.
+    pub fn deleted_ids(&self) -> &[StreamEntryId] {
+        &self.deleted_ids
+    }
+
+    fn fetch(&mut self) -> RedisResult<Vec<StreamId>> {
+        let reply = self.con.xautoclaim_options(
+            &self.key[..],
+            &self.group[..],
+            &self.consumer[..],
+            &self.min_idle_time[..],
+            self.cursor,
+            self.options,
+        )?;
+        self.cursor = reply.next_cursor;
+        if self.cursor == StreamEntryId::MIN {
+            self.done = true;
+        }
+        self.deleted_ids.extend(reply.deleted_ids);
+        Ok(reply.claimed)
+    }
+}
+
+impl<'a, C: ConnectionLike> Iterator for StreamAutoClaimIter<'a, C> {
+    type Item = RedisResult<StreamId>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(id) = self.page.next() {
+                return Some(Ok(id));
+            }
+            if self.done {
+                return None;
+            }
+            // a page can be empty while the cursor is still
+            // moving, so keep fetching until we get entries
+            match self.fetch() {
+                Ok(ids) => self.page = ids.into_iter(),
+                Err(e) => {
+                    self.done = true;
+                    return Some(Err(e));
+                }
+            }
+        }
+    }
+}
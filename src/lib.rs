@@ -19,6 +19,24 @@
 //! let mut con = client.get_connection().unwrap();
 //! ```
 //!
+//! The same commands are available on async connections through the
+//! `AsyncStreamCommands` trait.
+//!
+//! ```no_run
+//! use redis_streams::{client_open,AsyncStreamCommands};
+//! let client = client_open("redis://127.0.0.1/0").unwrap();
+//! let con = client.get_async_connection();
+//! ```
+//!
+//! With the `derive` feature, entries can be mapped to and from structs
+//! with `#[derive(FromStreamEntry, ToStreamEntry)]`.
+//!
+//! ```ini
+//! [dependencies.redis_streams]
+//! git = "https://github.com/grippy/redis-streams-rs.git"
+//! features = ["derive"]
+//! ```
+//!
 //! This crate also exposes all top-level `redis-rs` types.
 //! To pick up all `redis-rs` Commands, just use the `Commands` trait.
 //!
@@ -31,30 +49,82 @@
 #[doc(hidden)]
 pub use redis::{Commands, Connection, RedisResult};
 
+pub use crate::aio::AsyncStreamCommands;
 pub use crate::commands::StreamCommands;
+pub use crate::consumer::{GroupConsumer, ShutdownHandle};
+pub use crate::dead_letter::DeadLetterPolicy;
+pub use crate::entry::{FromStreamEntry, ToStreamEntry};
+pub use crate::error::StreamError;
+pub use crate::idempotent::IdempotentProducer;
+pub use crate::iter::{StreamAutoClaimIter, StreamRangeIter};
+pub use crate::pipeline::{StreamPipelineExt, StreamPipelineReplies};
+pub use crate::producer::{Backpressure, SendHandle, StreamProducer, StreamProducerOptions};
+pub use crate::tail::{StreamTail, StreamTailIter};
+
+#[cfg(feature = "derive")]
+pub use redis_streams_derive::{FromStreamEntry, ToStreamEntry};
+
+#[doc(hidden)]
+pub use crate::entry::derive as __derive;
 
 pub use crate::types::{
     // stream types
+    AddId,
+    GroupReadFrom,
+    NoGroup,
+    ParseStreamEntryIdError,
+    RangeBound,
+    ReadFrom,
+    ReadGroup,
+    StreamAddOptions,
+    StreamAutoClaimOptions,
+    StreamAutoClaimReply,
     StreamClaimOptions,
     StreamClaimReply,
+    StreamEntryId,
+    StreamFields,
+    StreamGroupCreateOptions,
     StreamId,
     StreamInfoConsumer,
     StreamInfoConsumersReply,
+    StreamInfoFullConsumer,
+    StreamInfoFullGroup,
+    StreamInfoFullPendingId,
     StreamInfoGroup,
     StreamInfoGroupsReply,
+    StreamInfoStreamFullReply,
     StreamInfoStreamReply,
     StreamKey,
     StreamMaxlen,
     StreamPendingCountReply,
     StreamPendingData,
     StreamPendingId,
+    StreamPendingOptions,
     StreamPendingReply,
     StreamRangeReply,
+    StreamReadBytesReply,
+    StreamReadMode,
     StreamReadOptions,
     StreamReadReply,
+    StreamSetIdOptions,
+    StreamTrimStrategy,
+    StreamTrimmingMode,
+    StreamTypedEntry,
+    StreamTypedReply,
 };
 
+mod aio;
 mod commands;
+mod consumer;
+mod dead_letter;
+mod entry;
+mod error;
+mod idempotent;
+mod iter;
+mod pipeline;
+mod producer;
+mod scripts;
+mod tail;
 mod types;
 
 /// Curry `redis::Client::open` calls.
@@ -0,0 +1,1041 @@
+use crate::idempotent::{dedup_key, IdempotentProducer};
+use crate::scripts::{XACK_AND_ADD, XADD_IDEMPOTENT};
+use crate::types::{
+    AddId, RangeBound, ReadFrom, StreamAddOptions, StreamAutoClaimOptions, StreamClaimOptions,
+    StreamEntryId, StreamGroupCreateOptions, StreamPendingOptions, StreamReadMode,
+    StreamReadOptions, StreamSetIdOptions, StreamTrimStrategy,
+};
+
+use redis::{
+    from_redis_value, FromRedisValue, Pipeline, RedisError, RedisResult, ToRedisArgs, Value,
+};
+
+use std::vec::IntoIter;
+
+/// Adds all redis stream commands to a `redis::Pipeline`,
+/// including atomic (`MULTI`/`EXEC`) pipelines.
+///
+/// Each method queues the same command as the `StreamCommands` version and
+/// returns the pipeline, so calls can be chained with `ignore()` and other
+/// commands. Use [`StreamPipelineReplies`] to decode the results into the
+/// typed replies, in the order the commands were queued.
+///
+/// Be advised: ids aren't checked before being queued, invalid ones are
+/// rejected by the server when the pipeline runs.
+///
+/// A few `StreamCommands` methods have no pipeline version:
+///
+/// - `xread_typed`, `xread_typed_options` and the `*_bytes` reads queue the
+///   same command as `xread`/`xread_options`/`xrange`. Queue that and pick
+///   the reply type in `next_reply`, e.g. `StreamTypedReply<T>`.
+/// - `xgroup_create_if_missing` has to swallow the `BUSYGROUP` error,
+///   which fails the whole pipeline. Queue `xgroup_create` on its own.
+/// - `xack_and_add_multi` is a `MULTI`/`EXEC` block already. Use an
+///   `atomic()` pipeline with `xack` and `xadd`.
+/// - The `*_iter` methods take a round trip per page.
+///
+/// ```no_run
+/// use redis_streams::{client_open,AddId,RangeBound,StreamEntryId,StreamMaxlen,StreamPipelineExt,StreamPipelineReplies,StreamRangeReply};
+/// let client = client_open("redis://127.0.0.1/0").unwrap();
+/// let mut con = client.get_connection().unwrap();
+///
+/// let mut pipe = redis::pipe();
+/// pipe.atomic()
+///     .xadd_maxlen("k1", StreamMaxlen::Equals(1000), AddId::Auto, &[("f", "v")])
+///     .xack("k2", "g1", &[StreamEntryId::new(1000, 0)])
+///     .ignore()
+///     .xrange_count("k1", RangeBound::Min, RangeBound::Max, 10);
+///
+/// let mut replies: StreamPipelineReplies = pipe.query(&mut con).unwrap();
+/// let id: StreamEntryId = replies.next_reply().unwrap();
+/// let range: StreamRangeReply = replies.next_reply().unwrap();
+/// ```
+///
+/// [`StreamPipelineReplies`]: ./struct.StreamPipelineReplies.html
+///
+pub trait StreamPipelineExt: Sized {
+    #[doc(hidden)]
+    fn as_pipeline(&mut self) -> &mut Pipeline;
+
+    // XACK <key> <group> <id> <id> ... <id>
+
+    /// Pipeline version of [`xack`].
+    ///
+    /// [`xack`]: ./trait.StreamCommands.html#method.xack
+    ///
+    #[inline]
+    fn xack<K: ToRedisArgs, G: ToRedisArgs, ID: ToRedisArgs>(
+        &mut self,
+        key: K,
+        group: G,
+        ids: &[ID],
+    ) -> &mut Self {
+        self.as_pipeline().cmd("XACK").arg(key).arg(group).arg(ids);
+        self
+    }
+
+    // XACK <src_key> <group> <id>
+    // XADD <dst_key> * [field value] ... (Lua script)
+
+    /// Pipeline version of [`xack_and_add`].
+    ///
+    /// [`xack_and_add`]: ./trait.StreamCommands.html#method.xack_and_add
+    ///
+    #[inline]
+    fn xack_and_add<
+        S: ToRedisArgs,
+        G: ToRedisArgs,
+        D: ToRedisArgs,
+        F: ToRedisArgs,
+        V: ToRedisArgs,
+    >(
+        &mut self,
+        src_key: S,
+        group: G,
+        id: StreamEntryId,
+        dst_key: D,
+        fields: &[(F, V)],
+    ) -> &mut Self {
+        self.as_pipeline()
+            .cmd("EVAL")
+            .arg(XACK_AND_ADD)
+            .arg(2)
+            .arg(src_key)
+            .arg(dst_key)
+            .arg(group)
+            .arg(id)
+            .arg(fields);
+        self
+    }
+
+    // XADD key <ID or *> [field value] [field value] ...
+
+    /// Pipeline version of [`xadd`].
+    ///
+    /// [`xadd`]: ./trait.StreamCommands.html#method.xadd
+    ///
+    #[inline]
+    fn xadd<K: ToRedisArgs, ID: Into<AddId>, F: ToRedisArgs, V: ToRedisArgs>(
+        &mut self,
+        key: K,
+        id: ID,
+        items: &[(F, V)],
+    ) -> &mut Self {
+        self.as_pipeline()
+            .cmd("XADD")
+            .arg(key)
+            .arg(id.into())
+            .arg(items);
+        self
+    }
+
+    // XADD key <ID or *> [rust BTreeMap] ...
+
+    /// Pipeline version of [`xadd_map`].
+    ///
+    /// [`xadd_map`]: ./trait.StreamCommands.html#method.xadd_map
+    ///
+    #[inline]
+    fn xadd_map<K: ToRedisArgs, ID: Into<AddId>, BTM: ToRedisArgs>(
+        &mut self,
+        key: K,
+        id: ID,
+        map: BTM,
+    ) -> &mut Self {
+        self.as_pipeline()
+            .cmd("XADD")
+            .arg(key)
+            .arg(id.into())
+            .arg(map);
+        self
+    }
+
+    // XADD key [MAXLEN|MINID [~|=] <threshold> [LIMIT <count>]] <ID or *> [field value] ...
+
+    /// Pipeline version of [`xadd_maxlen`].
+    ///
+    /// [`xadd_maxlen`]: ./trait.StreamCommands.html#method.xadd_maxlen
+    ///
+    #[inline]
+    fn xadd_maxlen<
+        K: ToRedisArgs,
+        T: Into<StreamTrimStrategy>,
+        ID: Into<AddId>,
+        F: ToRedisArgs,
+        V: ToRedisArgs,
+    >(
+        &mut self,
+        key: K,
+        trim: T,
+        id: ID,
+        items: &[(F, V)],
+    ) -> &mut Self {
+        self.as_pipeline()
+            .cmd("XADD")
+            .arg(key)
+            .arg(trim.into())
+            .arg(id.into())
+            .arg(items);
+        self
+    }
+
+    // XADD key [MAXLEN|MINID [~|=] <threshold> [LIMIT <count>]] <ID or *> [rust BTreeMap] ...
+
+    /// Pipeline version of [`xadd_maxlen_map`].
+    ///
+    /// [`xadd_maxlen_map`]: ./trait.StreamCommands.html#method.xadd_maxlen_map
+    ///
+    #[inline]
+    fn xadd_maxlen_map<
+        K: ToRedisArgs,
+        T: Into<StreamTrimStrategy>,
+        ID: Into<AddId>,
+        BTM: ToRedisArgs,
+    >(
+        &mut self,
+        key: K,
+        trim: T,
+        id: ID,
+        map: BTM,
+    ) -> &mut Self {
+        self.as_pipeline()
+            .cmd("XADD")
+            .arg(key)
+            .arg(trim.into())
+            .arg(id.into())
+            .arg(map);
+        self
+    }
+
+    // XADD key [NOMKSTREAM] [MAXLEN|MINID [~|=] <threshold> [LIMIT <count>]]
+    //     <ID or *> [field value] [field value] ...
+
+    /// Pipeline version of [`xadd_options`].
+    ///
+    /// [`xadd_options`]: ./trait.StreamCommands.html#method.xadd_options
+    ///
+    #[inline]
+    fn xadd_options<K: ToRedisArgs, F: ToRedisArgs, V: ToRedisArgs>(
+        &mut self,
+        key: K,
+        items: &[(F, V)],
+        options: StreamAddOptions,
+    ) -> &mut Self {
+        self.as_pipeline()
+            .cmd("XADD")
+            .arg(key)
+            .arg(options)
+            .arg(items);
+        self
+    }
+
+    // XADD key * [field value] ... producer-id <producer_id> producer-seq <seq> (Lua script)
+
+    /// Pipeline version of [`xadd_idempotent`].
+    ///
+    /// [`xadd_idempotent`]: ./trait.StreamCommands.html#method.xadd_idempotent
+    ///
+    #[inline]
+    fn xadd_idempotent<K: ToRedisArgs, P: ToRedisArgs, F: ToRedisArgs, V: ToRedisArgs>(
+        &mut self,
+        key: K,
+        producer_id: P,
+        seq: u64,
+        ttl: usize,
+        items: &[(F, V)],
+    ) -> &mut Self {
+        let key = key.to_redis_args().concat();
+        let producer_id = producer_id.to_redis_args().concat();
+        self.as_pipeline()
+            .cmd("EVAL")
+            .arg(XADD_IDEMPOTENT)
+            .arg(2)
+            .arg(&key[..])
+            .arg(dedup_key(&key, &producer_id, seq))
+            .arg(ttl)
+            .arg(items)
+            .arg(IdempotentProducer::PRODUCER_ID_FIELD)
+            .arg(&producer_id[..])
+            .arg(IdempotentProducer::SEQ_FIELD)
+            .arg(seq);
+        self
+    }
+
+    // XAUTOCLAIM <key> <group> <consumer> <min-idle-time> <start>
+
+    /// Pipeline version of [`xautoclaim`].
+    ///
+    /// [`xautoclaim`]: ./trait.StreamCommands.html#method.xautoclaim
+    ///
+    #[inline]
+    fn xautoclaim<K: ToRedisArgs, G: ToRedisArgs, C: ToRedisArgs, MIT: ToRedisArgs>(
+        &mut self,
+        key: K,
+        group: G,
+        consumer: C,
+        min_idle_time: MIT,
+        start: StreamEntryId,
+    ) -> &mut Self {
+        self.as_pipeline()
+            .cmd("XAUTOCLAIM")
+            .arg(key)
+            .arg(group)
+            .arg(consumer)
+            .arg(min_idle_time)
+            .arg(start);
+        self
+    }
+
+    // XAUTOCLAIM <key> <group> <consumer> <min-idle-time> <start>
+    //     [COUNT <count>] [JUSTID]
+
+    /// Pipeline version of [`xautoclaim_options`].
+    ///
+    /// [`xautoclaim_options`]: ./trait.StreamCommands.html#method.xautoclaim_options
+    ///
+    #[inline]
+    fn xautoclaim_options<K: ToRedisArgs, G: ToRedisArgs, C: ToRedisArgs, MIT: ToRedisArgs>(
+        &mut self,
+        key: K,
+        group: G,
+        consumer: C,
+        min_idle_time: MIT,
+        start: StreamEntryId,
+        options: StreamAutoClaimOptions,
+    ) -> &mut Self {
+        self.as_pipeline()
+            .cmd("XAUTOCLAIM")
+            .arg(key)
+            .arg(group)
+            .arg(consumer)
+            .arg(min_idle_time)
+            .arg(start)
+            .arg(options);
+        self
+    }
+
+    // XCLAIM <key> <group> <consumer> <min-idle-time> [<ID-1> <ID-2>]
+
+    /// Pipeline version of [`xclaim`].
+    ///
+    /// [`xclaim`]: ./trait.StreamCommands.html#method.xclaim
+    ///
+    #[inline]
+    fn xclaim<K: ToRedisArgs, G: ToRedisArgs, C: ToRedisArgs, MIT: ToRedisArgs, ID: ToRedisArgs>(
+        &mut self,
+        key: K,
+        group: G,
+        consumer: C,
+        min_idle_time: MIT,
+        ids: &[ID],
+    ) -> &mut Self {
+        self.as_pipeline()
+            .cmd("XCLAIM")
+            .arg(key)
+            .arg(group)
+            .arg(consumer)
+            .arg(min_idle_time)
+            .arg(ids);
+        self
+    }
+
+    // XCLAIM <key> <group> <consumer> <min-idle-time> <ID-1> <ID-2>
+    //     [IDLE <milliseconds>] [TIME <mstime>] [RETRYCOUNT <count>]
+    //     [FORCE] [JUSTID]
+
+    /// Pipeline version of [`xclaim_options`].
+    ///
+    /// [`xclaim_options`]: ./trait.StreamCommands.html#method.xclaim_options
+    ///
+    #[inline]
+    fn xclaim_options<
+        K: ToRedisArgs,
+        G: ToRedisArgs,
+        C: ToRedisArgs,
+        MIT: ToRedisArgs,
+        ID: ToRedisArgs,
+    >(
+        &mut self,
+        key: K,
+        group: G,
+        consumer: C,
+        min_idle_time: MIT,
+        ids: &[ID],
+        options: StreamClaimOptions,
+    ) -> &mut Self {
+        self.as_pipeline()
+            .cmd("XCLAIM")
+            .arg(key)
+            .arg(group)
+            .arg(consumer)
+            .arg(min_idle_time)
+            .arg(ids)
+            .arg(options);
+        self
+    }
+
+    // XDEL <key> [<ID1> <ID2> ... <IDN>]
+
+    /// Pipeline version of [`xdel`].
+    ///
+    /// [`xdel`]: ./trait.StreamCommands.html#method.xdel
+    ///
+    #[inline]
+    fn xdel<K: ToRedisArgs, ID: ToRedisArgs>(&mut self, key: K, ids: &[ID]) -> &mut Self {
+        self.as_pipeline().cmd("XDEL").arg(key).arg(ids);
+        self
+    }
+
+    // XGROUP CREATE <key> <groupname> <id or $>
+
+    /// Pipeline version of [`xgroup_create`].
+    ///
+    /// [`xgroup_create`]: ./trait.StreamCommands.html#method.xgroup_create
+    ///
+    #[inline]
+    fn xgroup_create<K: ToRedisArgs, G: ToRedisArgs, ID: Into<ReadFrom>>(
+        &mut self,
+        key: K,
+        group: G,
+        id: ID,
+    ) -> &mut Self {
+        self.as_pipeline()
+            .cmd("XGROUP")
+            .arg("CREATE")
+            .arg(key)
+            .arg(group)
+            .arg(id.into());
+        self
+    }
+
+    // XGROUP CREATE <key> <groupname> <id or $> [MKSTREAM]
+
+    /// Pipeline version of [`xgroup_create_mkstream`].
+    ///
+    /// [`xgroup_create_mkstream`]: ./trait.StreamCommands.html#method.xgroup_create_mkstream
+    ///
+    #[inline]
+    fn xgroup_create_mkstream<K: ToRedisArgs, G: ToRedisArgs, ID: Into<ReadFrom>>(
+        &mut self,
+        key: K,
+        group: G,
+        id: ID,
+    ) -> &mut Self {
+        self.as_pipeline()
+            .cmd("XGROUP")
+            .arg("CREATE")
+            .arg(key)
+            .arg(group)
+            .arg(id.into())
+            .arg("MKSTREAM");
+        self
+    }
+
+    // XGROUP CREATE <key> <groupname> <id or $> [MKSTREAM] [ENTRIESREAD <entries_read>]
+
+    /// Pipeline version of [`xgroup_create_options`].
+    ///
+    /// [`xgroup_create_options`]: ./trait.StreamCommands.html#method.xgroup_create_options
+    ///
+    #[inline]
+    fn xgroup_create_options<K: ToRedisArgs, G: ToRedisArgs>(
+        &mut self,
+        key: K,
+        group: G,
+        options: StreamGroupCreateOptions,
+    ) -> &mut Self {
+        self.as_pipeline()
+            .cmd("XGROUP")
+            .arg("CREATE")
+            .arg(key)
+            .arg(group)
+            .arg(options);
+        self
+    }
+
+    // XGROUP SETID <key> <groupname> <id or $>
+
+    /// Pipeline version of [`xgroup_setid`].
+    ///
+    /// [`xgroup_setid`]: ./trait.StreamCommands.html#method.xgroup_setid
+    ///
+    #[inline]
+    fn xgroup_setid<K: ToRedisArgs, G: ToRedisArgs, ID: Into<ReadFrom>>(
+        &mut self,
+        key: K,
+        group: G,
+        id: ID,
+    ) -> &mut Self {
+        self.as_pipeline()
+            .cmd("XGROUP")
+            .arg("SETID")
+            .arg(key)
+            .arg(group)
+            .arg(id.into());
+        self
+    }
+
+    // XGROUP SETID <key> <groupname> <id or $> ENTRIESREAD <entries_read>
+
+    /// Pipeline version of [`xgroup_setid_entries_read`].
+    ///
+    /// [`xgroup_setid_entries_read`]: ./trait.StreamCommands.html#method.xgroup_setid_entries_read
+    ///
+    #[inline]
+    fn xgroup_setid_entries_read<K: ToRedisArgs, G: ToRedisArgs, ID: Into<ReadFrom>>(
+        &mut self,
+        key: K,
+        group: G,
+        id: ID,
+        entries_read: usize,
+    ) -> &mut Self {
+        self.as_pipeline()
+            .cmd("XGROUP")
+            .arg("SETID")
+            .arg(key)
+            .arg(group)
+            .arg(id.into())
+            .arg("ENTRIESREAD")
+            .arg(entries_read);
+        self
+    }
+
+    // XGROUP DESTROY <key> <groupname>
+
+    /// Pipeline version of [`xgroup_destroy`].
+    ///
+    /// [`xgroup_destroy`]: ./trait.StreamCommands.html#method.xgroup_destroy
+    ///
+    #[inline]
+    fn xgroup_destroy<K: ToRedisArgs, G: ToRedisArgs>(&mut self, key: K, group: G) -> &mut Self {
+        self.as_pipeline()
+            .cmd("XGROUP")
+            .arg("DESTROY")
+            .arg(key)
+            .arg(group);
+        self
+    }
+
+    // XGROUP CREATECONSUMER <key> <groupname> <consumername>
+
+    /// Pipeline version of [`xgroup_createconsumer`].
+    ///
+    /// [`xgroup_createconsumer`]: ./trait.StreamCommands.html#method.xgroup_createconsumer
+    ///
+    #[inline]
+    fn xgroup_createconsumer<K: ToRedisArgs, G: ToRedisArgs, C: ToRedisArgs>(
+        &mut self,
+        key: K,
+        group: G,
+        consumer: C,
+    ) -> &mut Self {
+        self.as_pipeline()
+            .cmd("XGROUP")
+            .arg("CREATECONSUMER")
+            .arg(key)
+            .arg(group)
+            .arg(consumer);
+        self
+    }
+
+    // XGROUP DELCONSUMER <key> <groupname> <consumername>
+
+    /// Pipeline version of [`xgroup_delconsumer`].
+    ///
+    /// [`xgroup_delconsumer`]: ./trait.StreamCommands.html#method.xgroup_delconsumer
+    ///
+    #[inline]
+    fn xgroup_delconsumer<K: ToRedisArgs, G: ToRedisArgs, C: ToRedisArgs>(
+        &mut self,
+        key: K,
+        group: G,
+        consumer: C,
+    ) -> &mut Self {
+        self.as_pipeline()
+            .cmd("XGROUP")
+            .arg("DELCONSUMER")
+            .arg(key)
+            .arg(group)
+            .arg(consumer);
+        self
+    }
+
+    // XINFO CONSUMERS <key> <group>
+
+    /// Pipeline version of [`xinfo_consumers`].
+    ///
+    /// [`xinfo_consumers`]: ./trait.StreamCommands.html#method.xinfo_consumers
+    ///
+    #[inline]
+    fn xinfo_consumers<K: ToRedisArgs, G: ToRedisArgs>(&mut self, key: K, group: G) -> &mut Self {
+        self.as_pipeline()
+            .cmd("XINFO")
+            .arg("CONSUMERS")
+            .arg(key)
+            .arg(group);
+        self
+    }
+
+    // XINFO GROUPS <key>
+
+    /// Pipeline version of [`xinfo_groups`].
+    ///
+    /// [`xinfo_groups`]: ./trait.StreamCommands.html#method.xinfo_groups
+    ///
+    #[inline]
+    fn xinfo_groups<K: ToRedisArgs>(&mut self, key: K) -> &mut Self {
+        self.as_pipeline().cmd("XINFO").arg("GROUPS").arg(key);
+        self
+    }
+
+    // XINFO STREAM <key>
+
+    /// Pipeline version of [`xinfo_stream`].
+    ///
+    /// [`xinfo_stream`]: ./trait.StreamCommands.html#method.xinfo_stream
+    ///
+    #[inline]
+    fn xinfo_stream<K: ToRedisArgs>(&mut self, key: K) -> &mut Self {
+        self.as_pipeline().cmd("XINFO").arg("STREAM").arg(key);
+        self
+    }
+
+    // XINFO STREAM <key> FULL
+
+    /// Pipeline version of [`xinfo_stream_full`].
+    ///
+    /// [`xinfo_stream_full`]: ./trait.StreamCommands.html#method.xinfo_stream_full
+    ///
+    #[inline]
+    fn xinfo_stream_full<K: ToRedisArgs>(&mut self, key: K) -> &mut Self {
+        self.as_pipeline()
+            .cmd("XINFO")
+            .arg("STREAM")
+            .arg(key)
+            .arg("FULL");
+        self
+    }
+
+    // XINFO STREAM <key> FULL COUNT <count>
+
+    /// Pipeline version of [`xinfo_stream_full_count`].
+    ///
+    /// [`xinfo_stream_full_count`]: ./trait.StreamCommands.html#method.xinfo_stream_full_count
+    ///
+    #[inline]
+    fn xinfo_stream_full_count<K: ToRedisArgs, C: ToRedisArgs>(
+        &mut self,
+        key: K,
+        count: C,
+    ) -> &mut Self {
+        self.as_pipeline()
+            .cmd("XINFO")
+            .arg("STREAM")
+            .arg(key)
+            .arg("FULL")
+            .arg("COUNT")
+            .arg(count);
+        self
+    }
+
+    // XLEN <key>
+
+    /// Pipeline version of [`xlen`].
+    ///
+    /// [`xlen`]: ./trait.StreamCommands.html#method.xlen
+    ///
+    #[inline]
+    fn xlen<K: ToRedisArgs>(&mut self, key: K) -> &mut Self {
+        self.as_pipeline().cmd("XLEN").arg(key);
+        self
+    }
+
+    // XPENDING <key> <group> [<start> <stop> <count> [<consumer>]]
+
+    /// Pipeline version of [`xpending`].
+    ///
+    /// [`xpending`]: ./trait.StreamCommands.html#method.xpending
+    ///
+    #[inline]
+    fn xpending<K: ToRedisArgs, G: ToRedisArgs>(&mut self, key: K, group: G) -> &mut Self {
+        self.as_pipeline().cmd("XPENDING").arg(key).arg(group);
+        self
+    }
+
+    // XPENDING <key> <group> <start> <stop> <count>
+
+    /// Pipeline version of [`xpending_count`].
+    ///
+    /// [`xpending_count`]: ./trait.StreamCommands.html#method.xpending_count
+    ///
+    #[inline]
+    fn xpending_count<
+        K: ToRedisArgs,
+        G: ToRedisArgs,
+        S: Into<RangeBound>,
+        E: Into<RangeBound>,
+        C: ToRedisArgs,
+    >(
+        &mut self,
+        key: K,
+        group: G,
+        start: S,
+        end: E,
+        count: C,
+    ) -> &mut Self {
+        self.as_pipeline()
+            .cmd("XPENDING")
+            .arg(key)
+            .arg(group)
+            .arg(start.into())
+            .arg(end.into())
+            .arg(count);
+        self
+    }
+
+    // XPENDING <key> <group> <start> <stop> <count> <consumer>
+
+    /// Pipeline version of [`xpending_consumer_count`].
+    ///
+    /// [`xpending_consumer_count`]: ./trait.StreamCommands.html#method.xpending_consumer_count
+    ///
+    #[inline]
+    fn xpending_consumer_count<
+        K: ToRedisArgs,
+        G: ToRedisArgs,
+        S: Into<RangeBound>,
+        E: Into<RangeBound>,
+        C: ToRedisArgs,
+        CN: ToRedisArgs,
+    >(
+        &mut self,
+        key: K,
+        group: G,
+        start: S,
+        end: E,
+        count: C,
+        consumer: CN,
+    ) -> &mut Self {
+        self.as_pipeline()
+            .cmd("XPENDING")
+            .arg(key)
+            .arg(group)
+            .arg(start.into())
+            .arg(end.into())
+            .arg(count)
+            .arg(consumer);
+        self
+    }
+
+    // XPENDING <key> <group> IDLE <min-idle-time> <start> <stop> <count>
+
+    /// Pipeline version of [`xpending_idle_count`].
+    ///
+    /// [`xpending_idle_count`]: ./trait.StreamCommands.html#method.xpending_idle_count
+    ///
+    #[inline]
+    fn xpending_idle_count<
+        K: ToRedisArgs,
+        G: ToRedisArgs,
+        MIT: ToRedisArgs,
+        S: Into<RangeBound>,
+        E: Into<RangeBound>,
+        C: ToRedisArgs,
+    >(
+        &mut self,
+        key: K,
+        group: G,
+        min_idle_time: MIT,
+        start: S,
+        end: E,
+        count: C,
+    ) -> &mut Self {
+        self.as_pipeline()
+            .cmd("XPENDING")
+            .arg(key)
+            .arg(group)
+            .arg("IDLE")
+            .arg(min_idle_time)
+            .arg(start.into())
+            .arg(end.into())
+            .arg(count);
+        self
+    }
+
+    // XPENDING <key> <group> [IDLE <min-idle-time>] <start> <stop> <count> [<consumer>]
+
+    /// Pipeline version of [`xpending_options`].
+    ///
+    /// [`xpending_options`]: ./trait.StreamCommands.html#method.xpending_options
+    ///
+    #[inline]
+    fn xpending_options<K: ToRedisArgs, G: ToRedisArgs>(
+        &mut self,
+        key: K,
+        group: G,
+        options: StreamPendingOptions,
+    ) -> &mut Self {
+        self.as_pipeline()
+            .cmd("XPENDING")
+            .arg(key)
+            .arg(group)
+            .arg(options);
+        self
+    }
+
+    // XRANGE key start end
+
+    /// Pipeline version of [`xrange`].
+    ///
+    /// [`xrange`]: ./trait.StreamCommands.html#method.xrange
+    ///
+    #[inline]
+    fn xrange<K: ToRedisArgs, S: Into<RangeBound>, E: Into<RangeBound>>(
+        &mut self,
+        key: K,
+        start: S,
+        end: E,
+    ) -> &mut Self {
+        self.as_pipeline()
+            .cmd("XRANGE")
+            .arg(key)
+            .arg(start.into())
+            .arg(end.into());
+        self
+    }
+
+    // XRANGE key - +
+
+    /// Pipeline version of [`xrange_all`].
+    ///
+    /// [`xrange_all`]: ./trait.StreamCommands.html#method.xrange_all
+    ///
+    #[inline]
+    fn xrange_all<K: ToRedisArgs>(&mut self, key: K) -> &mut Self {
+        self.as_pipeline()
+            .cmd("XRANGE")
+            .arg(key)
+            .arg(RangeBound::Min)
+            .arg(RangeBound::Max);
+        self
+    }
+
+    // XRANGE key start end [COUNT <n>]
+
+    /// Pipeline version of [`xrange_count`].
+    ///
+    /// [`xrange_count`]: ./trait.StreamCommands.html#method.xrange_count
+    ///
+    #[inline]
+    fn xrange_count<K: ToRedisArgs, S: Into<RangeBound>, E: Into<RangeBound>, C: ToRedisArgs>(
+        &mut self,
+        key: K,
+        start: S,
+        end: E,
+        count: C,
+    ) -> &mut Self {
+        self.as_pipeline()
+            .cmd("XRANGE")
+            .arg(key)
+            .arg(start.into())
+            .arg(end.into())
+            .arg("COUNT")
+            .arg(count);
+        self
+    }
+
+    // XREAD STREAMS key_1 key_2 ... key_N ID_1 ID_2 ... ID_N
+
+    /// Pipeline version of [`xread`].
+    ///
+    /// [`xread`]: ./trait.StreamCommands.html#method.xread
+    ///
+    #[inline]
+    fn xread<K: ToRedisArgs>(&mut self, keys: &[K], ids: &[ReadFrom]) -> &mut Self {
+        self.as_pipeline()
+            .cmd("XREAD")
+            .arg("STREAMS")
+            .arg(keys)
+            .arg(ids);
+        self
+    }
+
+    // XREAD [BLOCK <milliseconds>] [COUNT <count>]
+    //       STREAMS key_1 key_2 ... key_N
+    //       ID_1 ID_2 ... ID_N
+    // XREADGROUP [BLOCK <milliseconds>] [COUNT <count>] [GROUP group-name consumer-name]
+    //       STREAMS key_1 key_2 ... key_N
+    //       ID_1 ID_2 ... ID_N
+
+    /// Pipeline version of [`xread_options`].
+    ///
+    /// [`xread_options`]: ./trait.StreamCommands.html#method.xread_options
+    ///
+    #[inline]
+    fn xread_options<K: ToRedisArgs, G: StreamReadMode>(
+        &mut self,
+        keys: &[K],
+        ids: &[G::Id],
+        options: StreamReadOptions<G>,
+    ) -> &mut Self {
+        self.as_pipeline()
+            .cmd(G::COMMAND)
+            .arg(options)
+            .arg("STREAMS")
+            .arg(keys)
+            .arg(ids);
+        self
+    }
+
+    // XREVRANGE key end start
+
+    /// Pipeline version of [`xrevrange`].
+    ///
+    /// [`xrevrange`]: ./trait.StreamCommands.html#method.xrevrange
+    ///
+    #[inline]
+    fn xrevrange<K: ToRedisArgs, E: Into<RangeBound>, S: Into<RangeBound>>(
+        &mut self,
+        key: K,
+        end: E,
+        start: S,
+    ) -> &mut Self {
+        self.as_pipeline()
+            .cmd("XREVRANGE")
+            .arg(key)
+            .arg(end.into())
+            .arg(start.into());
+        self
+    }
+
+    // XREVRANGE key + -
+
+    /// Pipeline version of [`xrevrange_all`].
+    ///
+    /// [`xrevrange_all`]: ./trait.StreamCommands.html#method.xrevrange_all
+    ///
+    #[inline]
+    fn xrevrange_all<K: ToRedisArgs>(&mut self, key: K) -> &mut Self {
+        self.as_pipeline()
+            .cmd("XREVRANGE")
+            .arg(key)
+            .arg(RangeBound::Max)
+            .arg(RangeBound::Min);
+        self
+    }
+
+    // XREVRANGE key end start [COUNT <n>]
+
+    /// Pipeline version of [`xrevrange_count`].
+    ///
+    /// [`xrevrange_count`]: ./trait.StreamCommands.html#method.xrevrange_count
+    ///
+    #[inline]
+    fn xrevrange_count<K: ToRedisArgs, E: Into<RangeBound>, S: Into<RangeBound>, C: ToRedisArgs>(
+        &mut self,
+        key: K,
+        end: E,
+        start: S,
+        count: C,
+    ) -> &mut Self {
+        self.as_pipeline()
+            .cmd("XREVRANGE")
+            .arg(key)
+            .arg(end.into())
+            .arg(start.into())
+            .arg("COUNT")
+            .arg(count);
+        self
+    }
+
+    // XSETID <key> <last-id>
+
+    /// Pipeline version of [`xsetid`].
+    ///
+    /// [`xsetid`]: ./trait.StreamCommands.html#method.xsetid
+    ///
+    #[inline]
+    fn xsetid<K: ToRedisArgs>(&mut self, key: K, id: StreamEntryId) -> &mut Self {
+        self.as_pipeline().cmd("XSETID").arg(key).arg(id);
+        self
+    }
+
+    // XSETID <key> <last-id> [ENTRIESADDED <entries_added>] [MAXDELETEDID <max_deleted_id>]
+
+    /// Pipeline version of [`xsetid_options`].
+    ///
+    /// [`xsetid_options`]: ./trait.StreamCommands.html#method.xsetid_options
+    ///
+    #[inline]
+    fn xsetid_options<K: ToRedisArgs>(
+        &mut self,
+        key: K,
+        id: StreamEntryId,
+        options: StreamSetIdOptions,
+    ) -> &mut Self {
+        self.as_pipeline()
+            .cmd("XSETID")
+            .arg(key)
+            .arg(id)
+            .arg(options);
+        self
+    }
+
+    // XTRIM <key> MAXLEN|MINID [~|=] <threshold> [LIMIT <count>]  (Same as XADD trim option)
+
+    /// Pipeline version of [`xtrim`].
+    ///
+    /// [`xtrim`]: ./trait.StreamCommands.html#method.xtrim
+    ///
+    #[inline]
+    fn xtrim<K: ToRedisArgs, T: Into<StreamTrimStrategy>>(&mut self, key: K, trim: T) -> &mut Self {
+        self.as_pipeline().cmd("XTRIM").arg(key).arg(trim.into());
+        self
+    }
+}
+
+impl StreamPipelineExt for Pipeline {
+    fn as_pipeline(&mut self) -> &mut Pipeline {
+        self
+    }
+}
+
+/// The results of a pipeline, decoded one command at a time.
+///
+/// Query the pipeline into this type, then call [`next_reply`] with
+/// the reply type of each command which isn't ignored, in order.
+///
+/// [`next_reply`]: #method.next_reply
+///
+#[derive(Debug)]
+pub struct StreamPipelineReplies {
+    values: IntoIter<Value>,
+}
+
+impl StreamPipelineReplies {
+    /// Decode the next result as `T`.
+    /// It's an error to ask for more results than the pipeline returned.
+    pub fn next_reply<T: FromRedisValue>(&mut self) -> RedisResult<T> {
+        match self.values.next() {
+            Some(ref value) => from_redis_value(value),
+            None => Err(RedisError::from((
+                redis::ErrorKind::TypeError,
+                "No more pipeline replies",
+            ))),
+        }
+    }
+
+    /// The number of results not yet decoded.
+    pub fn remaining(&self) -> usize {
+        self.values.len()
+    }
+}
+
+impl FromRedisValue for StreamPipelineReplies {
+    fn from_redis_value(v: &Value) -> RedisResult<Self> {
+        let values: Vec<Value> = from_redis_value(v)?;
+        Ok(StreamPipelineReplies {
+            values: values.into_iter(),
+        })
+    }
+}
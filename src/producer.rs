@@ -0,0 +1,457 @@
+use crate::scripts::XADD_BATCH;
+use crate::types::StreamEntryId;
+
+use futures::sync::oneshot;
+use futures::{Async, Future, Poll};
+use redis::{
+    cmd, from_redis_value, ConnectionLike, RedisError, RedisResult, Script, ToRedisArgs, Value,
+};
+
+use std::collections::VecDeque;
+use std::io;
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+/// What [`send`] does when the producer queue is full.
+///
+/// [`send`]: ./struct.StreamProducer.html#method.send
+///
+#[derive(PartialEq, Eq, Clone, Debug, Copy)]
+pub enum Backpressure {
+    /// Wait until a flush makes room.
+    Block,
+    /// Drop the oldest queued entry, whose handle resolves to an error.
+    DropOldest,
+    /// Return an error right away.
+    Error,
+}
+
+/// Builder options for [`StreamProducer`].
+///
+/// [`StreamProducer`]: ./struct.StreamProducer.html
+///
+#[derive(Clone, Debug)]
+pub struct StreamProducerOptions {
+    batch_size: usize,
+    linger: Duration,
+    capacity: usize,
+    backpressure: Backpressure,
+    maxlen: Option<usize>,
+}
+
+impl Default for StreamProducerOptions {
+    fn default() -> Self {
+        StreamProducerOptions {
+            batch_size: 100,
+            linger: Duration::from_millis(10),
+            capacity: 10_000,
+            backpressure: Backpressure::Block,
+            maxlen: None,
+        }
+    }
+}
+
+impl StreamProducerOptions {
+    /// Flush once this many entries are queued. Defaults to 100.
+    pub fn batch_size(mut self, n: usize) -> Self {
+        self.batch_size = n.max(1);
+        self
+    }
+
+    /// Flush once the oldest queued entry has waited `ms`. Defaults to 10ms.
+    pub fn linger(mut self, ms: usize) -> Self {
+        self.linger = Duration::from_millis(ms as u64);
+        self
+    }
+
+    /// The max number of entries waiting to be flushed. Defaults to 10000.
+    pub fn capacity(mut self, n: usize) -> Self {
+        self.capacity = n.max(1);
+        self
+    }
+
+    /// What to do when `capacity` is reached. Defaults to `Backpressure::Block`.
+    pub fn backpressure(mut self, backpressure: Backpressure) -> Self {
+        self.backpressure = backpressure;
+        self
+    }
+
+    /// Trim every key written to with `MAXLEN ~ n` on each flush.
+    pub fn maxlen(mut self, n: usize) -> Self {
+        self.maxlen = Some(n);
+        self
+    }
+}
+
+/// Future returned by [`send`] which resolves to the id of the entry
+/// once it's written, or to the error which kept it from being written.
+///
+/// Use `Future::wait` to block on it.
+///
+/// [`send`]: ./struct.StreamProducer.html#method.send
+///
+#[derive(Debug)]
+pub struct SendHandle(oneshot::Receiver<RedisResult<StreamEntryId>>);
+
+impl Future for SendHandle {
+    type Item = StreamEntryId;
+    type Error = RedisError;
+
+    fn poll(&mut self) -> Poll<StreamEntryId, RedisError> {
+        match self.0.poll() {
+            Ok(Async::Ready(Ok(id))) => Ok(Async::Ready(id)),
+            Ok(Async::Ready(Err(e))) => Err(e),
+            Ok(Async::NotReady) => Ok(Async::NotReady),
+            Err(_) => Err(closed_error()),
+        }
+    }
+}
+
+struct Pending {
+    key: Vec<u8>,
+    fields: Vec<Vec<u8>>,
+    queued: Instant,
+    tx: oneshot::Sender<RedisResult<StreamEntryId>>,
+}
+
+#[derive(Default)]
+struct State {
+    queue: VecDeque<Pending>,
+    in_flight: usize,
+    flush_requested: bool,
+    closed: bool,
+    worker_gone: bool,
+}
+
+struct Shared {
+    state: Mutex<State>,
+    cond: Condvar,
+}
+
+impl Shared {
+    fn lock(&self) -> MutexGuard<'_, State> {
+        // a panic while holding the lock can't leave the queue half updated
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn wait<'a>(&self, guard: MutexGuard<'a, State>) -> MutexGuard<'a, State> {
+        self.cond.wait(guard).unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Batches `XADD`s from any number of threads and writes them from a
+/// background thread, with one pipeline per flush.
+///
+/// Entries are queued by [`send`] and flushed once `batch_size` entries
+/// are queued or the oldest one has waited `linger`. Entries for the same
+/// key are written in the order they were sent, by a Lua script per key
+/// which is loaded once and run with `EVALSHA`.
+/// When `maxlen` is set, every key in a flush is trimmed with
+/// `XTRIM <key> MAXLEN ~ <n>`.
+///
+/// Each handle resolves on its own, so an entry the server rejects (e.g.
+/// for a key which isn't a stream) doesn't fail the rest of the flush.
+/// If the flush itself fails, every entry of that flush gets the error,
+/// though some of them may have been written.
+///
+/// ```no_run
+/// use futures::Future;
+/// use redis_streams::{client_open,Backpressure,StreamProducer,StreamProducerOptions};
+/// let client = client_open("redis://127.0.0.1/0").unwrap();
+/// let con = client.get_connection().unwrap();
+///
+/// let opts = StreamProducerOptions::default()
+///     .batch_size(500)
+///     .linger(5)
+///     .backpressure(Backpressure::DropOldest)
+///     .maxlen(100000);
+/// let producer = StreamProducer::start(con, opts);
+///
+/// let handle = producer.send("telemetry", &[("cpu", "0.93")]).unwrap();
+/// let id = handle.wait().unwrap();
+/// producer.close();
+/// ```
+///
+/// [`send`]: #method.send
+///
+pub struct StreamProducer {
+    shared: Arc<Shared>,
+    options: StreamProducerOptions,
+    worker: Option<JoinHandle<()>>,
+}
+
+impl StreamProducer {
+    /// Start the background thread, which owns `con` until the producer is closed.
+    pub fn start<C: ConnectionLike + Send + 'static>(
+        con: C,
+        options: StreamProducerOptions,
+    ) -> Self {
+        let shared = Arc::new(Shared {
+            state: Mutex::new(State::default()),
+            cond: Condvar::new(),
+        });
+        let worker = {
+            let shared = shared.clone();
+            let options = options.clone();
+            thread::spawn(move || run(con, &shared, &options))
+        };
+        StreamProducer {
+            shared,
+            options,
+            worker: Some(worker),
+        }
+    }
+
+    /// Queue an entry for `key`. The returned handle resolves once the
+    /// entry is written. Fails if the producer is closed, if the background
+    /// thread has stopped (e.g. after a panic) or, depending on the
+    /// `Backpressure` policy, if the queue is full.
+    pub fn send<K: ToRedisArgs, F: ToRedisArgs, V: ToRedisArgs>(
+        &self,
+        key: K,
+        fields: &[(F, V)],
+    ) -> RedisResult<SendHandle> {
+        let (tx, rx) = oneshot::channel();
+        let pending = Pending {
+            key: key.to_redis_args().concat(),
+            fields: fields.to_redis_args(),
+            queued: Instant::now(),
+            tx,
+        };
+
+        let mut state = self.shared.lock();
+        loop {
+            if state.closed {
+                return Err(closed_error());
+            }
+            // nothing would write the entry, or make room for it
+            if state.worker_gone {
+                return Err(worker_gone_error());
+            }
+            if state.queue.len() < self.options.capacity {
+                break;
+            }
+            match self.options.backpressure {
+                Backpressure::Block => state = self.shared.wait(state),
+                Backpressure::DropOldest => {
+                    if let Some(dropped) = state.queue.pop_front() {
+                        let _ = dropped.tx.send(Err(RedisError::from(io::Error::new(
+                            io::ErrorKind::Other,
+                            "stream producer dropped the entry",
+                        ))));
+                    }
+                }
+                Backpressure::Error => {
+                    return Err(RedisError::from(io::Error::new(
+                        io::ErrorKind::WouldBlock,
+                        "stream producer queue is full",
+                    )));
+                }
+            }
+        }
+        state.queue.push_back(pending);
+        // the worker waits without a timeout while the queue is empty,
+        // so it has to be woken to start the linger clock
+        if state.queue.len() == 1 || state.queue.len() >= self.options.batch_size {
+            self.shared.cond.notify_all();
+        }
+        Ok(SendHandle(rx))
+    }
+
+    /// Flush now and wait until every entry sent before is written.
+    pub fn flush(&self) {
+        let mut state = self.shared.lock();
+        if state.queue.is_empty() && state.in_flight == 0 {
+            // the flag would skip `linger` for the next entry
+            return;
+        }
+        state.flush_requested = true;
+        self.shared.cond.notify_all();
+        while !state.closed
+            && !state.worker_gone
+            && (!state.queue.is_empty() || state.in_flight > 0)
+        {
+            state = self.shared.wait(state);
+        }
+    }
+
+    /// Write the queued entries and stop the background thread.
+    pub fn close(mut self) {
+        self.stop();
+    }
+
+    fn stop(&mut self) {
+        self.shared.lock().closed = true;
+        self.shared.cond.notify_all();
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+    }
+}
+
+impl Drop for StreamProducer {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+/// Marks the worker as gone once `run` returns or panics, so senders
+/// don't wait for it forever, and fails what's left in the queue.
+struct WorkerGuard<'a>(&'a Shared);
+
+impl Drop for WorkerGuard<'_> {
+    fn drop(&mut self) {
+        let mut state = self.0.lock();
+        state.worker_gone = true;
+        for pending in state.queue.drain(..) {
+            let _ = pending.tx.send(Err(worker_gone_error()));
+        }
+        self.0.cond.notify_all();
+    }
+}
+
+fn run<C: ConnectionLike>(mut con: C, shared: &Shared, options: &StreamProducerOptions) {
+    let _guard = WorkerGuard(shared);
+    let script = Script::new(XADD_BATCH);
+    // if this fails, the first flush loads it on NOSCRIPT
+    let _ = load_script(&mut con);
+
+    while let Some(batch) = next_batch(shared, options) {
+        write_batch(&mut con, &script, batch, options);
+
+        let mut state = shared.lock();
+        state.in_flight = 0;
+        if state.queue.is_empty() {
+            state.flush_requested = false;
+        }
+        shared.cond.notify_all();
+    }
+}
+
+/// Wait for the next batch to be due. Returns `None` once
+/// the producer is closed and the queue is empty.
+fn next_batch(shared: &Shared, options: &StreamProducerOptions) -> Option<Vec<Pending>> {
+    let mut state = shared.lock();
+    loop {
+        let age = match state.queue.front() {
+            Some(pending) => pending.queued.elapsed(),
+            None if state.closed => return None,
+            None => {
+                state = shared.wait(state);
+                continue;
+            }
+        };
+        if state.closed
+            || state.flush_requested
+            || state.queue.len() >= options.batch_size
+            || age >= options.linger
+        {
+            break;
+        }
+        state = shared
+            .cond
+            .wait_timeout(state, options.linger - age)
+            .map(|(guard, _)| guard)
+            .unwrap_or_else(|e| e.into_inner().0);
+    }
+
+    let n = state.queue.len().min(options.batch_size);
+    let batch: Vec<Pending> = state.queue.drain(..n).collect();
+    state.in_flight = batch.len();
+    // senders blocked on a full queue can go on
+    shared.cond.notify_all();
+    Some(batch)
+}
+
+fn load_script<C: ConnectionLike>(con: &mut C) -> RedisResult<()> {
+    cmd("SCRIPT")
+        .arg("LOAD")
+        .arg(XADD_BATCH)
+        .query::<String>(con)?;
+    Ok(())
+}
+
+fn write_batch<C: ConnectionLike>(
+    con: &mut C,
+    script: &Script,
+    batch: Vec<Pending>,
+    options: &StreamProducerOptions,
+) {
+    // group by key, keeping the order of the entries for each key
+    let mut keys: Vec<(Vec<u8>, Vec<Pending>)> = Vec::new();
+    for pending in batch {
+        match keys.iter().position(|(key, _)| *key == pending.key) {
+            Some(idx) => keys[idx].1.push(pending),
+            None => keys.push((pending.key.clone(), vec![pending])),
+        }
+    }
+
+    // with MULTI/EXEC, a NOSCRIPT error comes back in a single reply, so
+    // it doesn't leave the other replies unread and nothing was written
+    let mut pipe = redis::pipe();
+    pipe.atomic();
+    for (key, entries) in &keys {
+        let cmd = pipe
+            .cmd("EVALSHA")
+            .arg(script.get_hash())
+            .arg(1)
+            .arg(&key[..])
+            .arg(options.maxlen.map(|n| n.to_string()).unwrap_or_default());
+        for pending in entries {
+            cmd.arg(pending.fields.len()).arg(&pending.fields[..]);
+        }
+    }
+
+    let mut result = pipe.query::<Vec<Vec<Value>>>(con);
+    if let Err(ref e) = result {
+        if e.kind() == redis::ErrorKind::NoScriptError && load_script(con).is_ok() {
+            result = pipe.query(con);
+        }
+    }
+
+    match result {
+        Ok(replies) => {
+            for ((_, entries), ids) in keys.into_iter().zip(replies) {
+                for (pending, id) in entries.into_iter().zip(ids) {
+                    let _ = pending.tx.send(entry_result(&id));
+                }
+            }
+        }
+        Err(e) => {
+            for pending in keys.into_iter().flat_map(|(_, entries)| entries) {
+                let _ = pending.tx.send(Err(RedisError::from((
+                    e.kind(),
+                    "Stream producer flush failed",
+                    e.to_string(),
+                ))));
+            }
+        }
+    }
+}
+
+/// An id, or the `{error}` the script returns for an entry which wasn't added.
+fn entry_result(v: &Value) -> RedisResult<StreamEntryId> {
+    match v {
+        Value::Bulk(err) => Err(RedisError::from((
+            redis::ErrorKind::ResponseError,
+            "Stream producer XADD failed",
+            from_redis_value::<String>(err.first().unwrap_or(&Value::Nil))?,
+        ))),
+        _ => from_redis_value(v),
+    }
+}
+
+fn worker_gone_error() -> RedisError {
+    RedisError::from(io::Error::new(
+        io::ErrorKind::BrokenPipe,
+        "stream producer worker has stopped",
+    ))
+}
+
+fn closed_error() -> RedisError {
+    RedisError::from(io::Error::new(
+        io::ErrorKind::BrokenPipe,
+        "stream producer is closed",
+    ))
+}
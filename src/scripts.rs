@@ -0,0 +1,58 @@
+/// KEYS: source key, destination key.
+/// ARGV: group, id, field, value, ...
+///
+/// Acks `id` on the source stream and, only if it was pending, adds the
+/// fields to the destination stream. Returns the new id, or nil when
+/// nothing was acked.
+pub(crate) const XACK_AND_ADD: &str = r#"
+if redis.call('XACK', KEYS[1], ARGV[1], ARGV[2]) == 0 then
+    return false
+end
+return redis.call('XADD', KEYS[2], '*', unpack(ARGV, 3))
+"#;
+
+/// KEYS: stream key, dedup key.
+/// ARGV: ttl in ms, field, value, ...
+///
+/// Adds the fields to the stream unless the dedup key is set, in which
+/// case it holds the id of the entry added the first time. Returns the id.
+/// A ttl which isn't above 0 is rejected before anything is added, since
+/// `SET ... PX 0` would fail after the `XADD`.
+pub(crate) const XADD_IDEMPOTENT: &str = r#"
+local ttl = tonumber(ARGV[1])
+if not ttl or ttl <= 0 then
+    return redis.error_reply('ERR invalid ttl for xadd_idempotent')
+end
+local id = redis.call('GET', KEYS[2])
+if id then
+    return id
+end
+id = redis.call('XADD', KEYS[1], '*', unpack(ARGV, 2))
+redis.call('SET', KEYS[2], id, 'PX', ARGV[1])
+return id
+"#;
+
+/// KEYS: stream key.
+/// ARGV: maxlen or "", then for each entry: n, followed by n field/value args.
+///
+/// Adds every entry to the stream and trims it with `MAXLEN ~` if a
+/// maxlen is given. Returns the id of each entry, or `{error}` for one
+/// which couldn't be added, since an error reply would fail the whole
+/// pipeline.
+pub(crate) const XADD_BATCH: &str = r#"
+local ids = {}
+local i = 2
+while i <= #ARGV do
+    local n = tonumber(ARGV[i])
+    local id = redis.pcall('XADD', KEYS[1], '*', unpack(ARGV, i + 1, i + n))
+    if type(id) == 'table' then
+        id = {id.err}
+    end
+    ids[#ids + 1] = id
+    i = i + n + 1
+end
+if ARGV[1] ~= '' then
+    redis.pcall('XTRIM', KEYS[1], 'MAXLEN', '~', ARGV[1])
+end
+return ids
+"#;
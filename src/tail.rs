@@ -0,0 +1,228 @@
+use crate::aio::AsyncStreamCommands;
+use crate::commands::StreamCommands;
+use crate::types::{ReadFrom, StreamId, StreamReadOptions, StreamReadReply};
+
+use futures::{Async, Future, Poll, Stream};
+use redis::{ConnectionLike, RedisError, RedisFuture, RedisResult, ToRedisArgs};
+
+use std::collections::VecDeque;
+use std::mem;
+
+/// Bookkeeping shared by the blocking and async tails: the keys,
+/// the last position read for each key and the entries not yet returned.
+struct TailState {
+    keys: Vec<Vec<u8>>,
+    positions: Vec<ReadFrom>,
+    block: usize,
+    count: usize,
+    buffer: VecDeque<(Option<usize>, String, StreamId)>,
+    last_key: Option<String>,
+    error: Option<RedisError>,
+}
+
+impl TailState {
+    fn new<K: ToRedisArgs>(keys: &[K], ids: &[ReadFrom], block: usize, count: usize) -> Self {
+        let keys = keys
+            .iter()
+            .flat_map(|k| k.to_redis_args())
+            .collect::<Vec<Vec<u8>>>();
+        let error = if keys.len() != ids.len() {
+            Some(RedisError::from((
+                redis::ErrorKind::TypeError,
+                "Stream tail expects one id per key",
+            )))
+        } else {
+            None
+        };
+        TailState {
+            keys,
+            positions: ids.to_vec(),
+            block,
+            count: count.max(1),
+            buffer: VecDeque::new(),
+            last_key: None,
+            error,
+        }
+    }
+
+    fn options(&self) -> StreamReadOptions {
+        StreamReadOptions::default()
+            .block(self.block)
+            .count(self.count)
+    }
+
+    fn push_reply(&mut self, reply: StreamReadReply) {
+        for stream_key in reply.keys {
+            let idx = self
+                .keys
+                .iter()
+                .position(|k| k[..] == *stream_key.key.as_bytes());
+            for id in stream_key.ids {
+                self.buffer.push_back((idx, stream_key.key.clone(), id));
+            }
+        }
+    }
+
+    fn pop(&mut self) -> Option<StreamId> {
+        self.buffer.pop_front().map(|(idx, key, id)| {
+            // only move past entries once they're returned, so the
+            // positions never skip the ones still buffered
+            if let Some(idx) = idx {
+                self.positions[idx] = ReadFrom::After(id.id);
+            }
+            self.last_key = Some(key);
+            id
+        })
+    }
+}
+
+/// Iterator returned by [`xread_iter`] which tails one or more streams
+/// with a blocking `XREAD`.
+///
+/// The iterator never ends on its own. It keeps the last `id` read for
+/// each key, so after an error (or a reconnect) a new tail can resume
+/// from [`positions`] without skipping or repeating entries.
+///
+/// Be advised: when starting from `ReadFrom::New`, entries added between
+/// two calls while no entry has been read yet for that key can be missed.
+/// Start from a concrete id if every entry matters.
+///
+/// [`xread_iter`]: ./trait.StreamCommands.html#method.xread_iter
+/// [`positions`]: #method.positions
+///
+pub struct StreamTailIter<'a, C: ConnectionLike> {
+    con: &'a mut C,
+    state: TailState,
+}
+
+impl<'a, C: ConnectionLike> StreamTailIter<'a, C> {
+    pub(crate) fn new<K: ToRedisArgs>(
+        con: &'a mut C,
+        keys: &[K],
+        ids: &[ReadFrom],
+        block: usize,
+        count: usize,
+    ) -> Self {
+        StreamTailIter {
+            con,
+            state: TailState::new(keys, ids, block, count),
+        }
+    }
+
+    /// The position to resume from for each key, in the order the keys were given.
+    pub fn positions(&self) -> &[ReadFrom] {
+        &self.state.positions
+    }
+
+    /// The key of the entry most recently returned.
+    pub fn last_key(&self) -> Option<&str> {
+        self.state.last_key.as_ref().map(|k| &k[..])
+    }
+}
+
+impl<'a, C: ConnectionLike> Iterator for StreamTailIter<'a, C> {
+    type Item = RedisResult<StreamId>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(e) = self.state.error.take() {
+            return Some(Err(e));
+        }
+        loop {
+            if let Some(id) = self.state.pop() {
+                return Some(Ok(id));
+            }
+            let options = self.state.options();
+            match self
+                .con
+                .xread_options(&self.state.keys, &self.state.positions, options)
+            {
+                Ok(reply) => self.state.push_reply(reply),
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
+
+enum TailConnection<C> {
+    Idle(C),
+    Reading(RedisFuture<(C, StreamReadReply)>),
+    Closed,
+}
+
+/// Async `Stream` returned by [`xread_stream`] which tails one or more
+/// streams with a blocking `XREAD`.
+///
+/// This works the same way as `StreamTailIter`, except that it owns the
+/// connection. An error closes the connection and ends the stream, so
+/// use [`positions`] to resume on a new connection.
+///
+/// [`xread_stream`]: ./trait.AsyncStreamCommands.html#method.xread_stream
+/// [`positions`]: #method.positions
+///
+pub struct StreamTail<C: AsyncStreamCommands> {
+    con: TailConnection<C>,
+    state: TailState,
+}
+
+impl<C: AsyncStreamCommands> StreamTail<C> {
+    pub(crate) fn new<K: ToRedisArgs>(
+        con: C,
+        keys: &[K],
+        ids: &[ReadFrom],
+        block: usize,
+        count: usize,
+    ) -> Self {
+        StreamTail {
+            con: TailConnection::Idle(con),
+            state: TailState::new(keys, ids, block, count),
+        }
+    }
+
+    /// The position to resume from for each key, in the order the keys were given.
+    pub fn positions(&self) -> &[ReadFrom] {
+        &self.state.positions
+    }
+
+    /// The key of the entry most recently returned.
+    pub fn last_key(&self) -> Option<&str> {
+        self.state.last_key.as_ref().map(|k| &k[..])
+    }
+}
+
+impl<C: AsyncStreamCommands> Stream for StreamTail<C> {
+    type Item = StreamId;
+    type Error = RedisError;
+
+    fn poll(&mut self) -> Poll<Option<StreamId>, RedisError> {
+        if let Some(e) = self.state.error.take() {
+            self.con = TailConnection::Closed;
+            return Err(e);
+        }
+        loop {
+            if let Some(id) = self.state.pop() {
+                return Ok(Async::Ready(Some(id)));
+            }
+            match mem::replace(&mut self.con, TailConnection::Closed) {
+                TailConnection::Idle(con) => {
+                    let options = self.state.options();
+                    self.con = TailConnection::Reading(con.xread_options(
+                        &self.state.keys,
+                        &self.state.positions,
+                        options,
+                    ));
+                }
+                TailConnection::Reading(mut future) => match future.poll()? {
+                    Async::Ready((con, reply)) => {
+                        self.state.push_reply(reply);
+                        self.con = TailConnection::Idle(con);
+                    }
+                    Async::NotReady => {
+                        self.con = TailConnection::Reading(future);
+                        return Ok(Async::NotReady);
+                    }
+                },
+                TailConnection::Closed => return Ok(Async::Ready(None)),
+            }
+        }
+    }
+}
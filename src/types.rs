@@ -1,7 +1,303 @@
-use redis::{from_redis_value, FromRedisValue, RedisResult, RedisWrite, ToRedisArgs, Value};
+use crate::entry::FromStreamEntry;
+
+use redis::{
+    from_redis_value, FromRedisValue, RedisError, RedisResult, RedisWrite, ToRedisArgs, Value,
+};
 
 use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
 use std::io::{Error, ErrorKind};
+use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+// Stream Entry Id
+
+/// Represents a stream entry `id` in the form of `<ms>-<seq>`.
+///
+/// Ids are ordered the same way Redis orders them: first by
+/// the millisecond timestamp, then by the sequence number.
+///
+/// ```
+/// use redis_streams::StreamEntryId;
+/// let id: StreamEntryId = "1000-1".parse().unwrap();
+/// assert_eq!(id, StreamEntryId::new(1000, 1));
+/// assert_eq!(id.to_string(), "1000-1");
+/// assert!(id < StreamEntryId::new(1001, 0));
+/// ```
+///
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct StreamEntryId {
+    /// The millisecond timestamp part of the id.
+    pub ms: u64,
+    /// The sequence number part of the id.
+    pub seq: u64,
+}
+
+impl StreamEntryId {
+    /// The smallest possible id (`0-0`).
+    pub const MIN: StreamEntryId = StreamEntryId { ms: 0, seq: 0 };
+
+    /// The largest possible id.
+    pub const MAX: StreamEntryId = StreamEntryId {
+        ms: u64::MAX,
+        seq: u64::MAX,
+    };
+
+    pub fn new(ms: u64, seq: u64) -> Self {
+        StreamEntryId { ms, seq }
+    }
+
+    /// Returns the first id for a given millisecond timestamp (`<ms>-0`).
+    pub fn from_millis(ms: u64) -> Self {
+        StreamEntryId { ms, seq: 0 }
+    }
+
+    /// Returns the id immediately following this one, or `None`
+    /// if this is already `StreamEntryId::MAX`.
+    pub fn next(&self) -> Option<Self> {
+        if self.seq < u64::MAX {
+            Some(StreamEntryId::new(self.ms, self.seq + 1))
+        } else if self.ms < u64::MAX {
+            Some(StreamEntryId::new(self.ms + 1, 0))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the id immediately preceding this one, or `None`
+    /// if this is already `StreamEntryId::MIN`.
+    pub fn prev(&self) -> Option<Self> {
+        if self.seq > 0 {
+            Some(StreamEntryId::new(self.ms, self.seq - 1))
+        } else if self.ms > 0 {
+            Some(StreamEntryId::new(self.ms - 1, u64::MAX))
+        } else {
+            None
+        }
+    }
+}
+
+impl fmt::Display for StreamEntryId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}-{}", self.ms, self.seq)
+    }
+}
+
+/// Error returned when parsing a `StreamEntryId` from a string fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseStreamEntryIdError {
+    input: String,
+}
+
+impl fmt::Display for ParseStreamEntryIdError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid stream entry id: {:?}", self.input)
+    }
+}
+
+impl std::error::Error for ParseStreamEntryIdError {}
+
+impl FromStr for StreamEntryId {
+    type Err = ParseStreamEntryIdError;
+
+    /// Parses `<ms>-<seq>`. A bare `<ms>` is accepted as `<ms>-0`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParseStreamEntryIdError {
+            input: s.to_string(),
+        };
+        let mut parts = s.splitn(2, '-');
+        let ms = parts
+            .next()
+            .and_then(|ms| ms.parse::<u64>().ok())
+            .ok_or_else(err)?;
+        let seq = match parts.next() {
+            Some(seq) => seq.parse::<u64>().map_err(|_| err())?,
+            None => 0,
+        };
+        Ok(StreamEntryId::new(ms, seq))
+    }
+}
+
+impl From<SystemTime> for StreamEntryId {
+    /// Converts a `SystemTime` to the first id for its millisecond.
+    /// Times before the unix epoch map to `StreamEntryId::MIN`.
+    fn from(time: SystemTime) -> Self {
+        let ms = time
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs() * 1000 + u64::from(d.subsec_millis()))
+            .unwrap_or(0);
+        StreamEntryId::from_millis(ms)
+    }
+}
+
+impl ToRedisArgs for StreamEntryId {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        out.write_arg(self.to_string().as_bytes());
+    }
+}
+
+impl FromRedisValue for StreamEntryId {
+    fn from_redis_value(v: &Value) -> RedisResult<Self> {
+        let s: String = from_redis_value(v)?;
+        s.parse().map_err(|_| {
+            RedisError::from((
+                redis::ErrorKind::TypeError,
+                "Response was not a valid stream entry id",
+                s,
+            ))
+        })
+    }
+}
+
+// Stream Id Arguments
+
+/// The `id` argument for `XADD` commands.
+///
+/// `AddId::Auto` lets the server generate an id (`*`),
+/// while `AddId::Explicit` passes a specific `StreamEntryId`.
+#[derive(PartialEq, Eq, Clone, Debug, Copy)]
+pub enum AddId {
+    Auto,
+    Explicit(StreamEntryId),
+}
+
+impl From<StreamEntryId> for AddId {
+    fn from(id: StreamEntryId) -> Self {
+        AddId::Explicit(id)
+    }
+}
+
+impl ToRedisArgs for AddId {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        match *self {
+            AddId::Auto => out.write_arg("*".as_bytes()),
+            AddId::Explicit(ref id) => id.write_redis_args(out),
+        }
+    }
+}
+
+/// The per-stream `id` argument for `XREAD` and the `XGROUP CREATE|SETID` commands.
+///
+/// - `ReadFrom::New` (`$`) only returns entries added after the call.
+/// - `ReadFrom::After(id)` returns entries greater than `id`. Use
+///   `StreamEntryId::MIN` to start from the beginning (`0`).
+///
+/// `XREADGROUP` takes a [`GroupReadFrom`] instead, so `>` can't be
+/// passed to `XREAD`:
+///
+/// ```compile_fail
+/// use redis_streams::{client_open,GroupReadFrom,StreamCommands,StreamReadReply};
+/// let client = client_open("redis://127.0.0.1/0").unwrap();
+/// let mut con = client.get_connection().unwrap();
+/// let reply: StreamReadReply = con.xread(&["k1"], &[GroupReadFrom::Undelivered]).unwrap();
+/// ```
+///
+/// [`GroupReadFrom`]: ./enum.GroupReadFrom.html
+///
+#[derive(PartialEq, Eq, Clone, Debug, Copy)]
+pub enum ReadFrom {
+    New,
+    After(StreamEntryId),
+}
+
+impl From<StreamEntryId> for ReadFrom {
+    fn from(id: StreamEntryId) -> Self {
+        ReadFrom::After(id)
+    }
+}
+
+impl ToRedisArgs for ReadFrom {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        match *self {
+            ReadFrom::New => out.write_arg("$".as_bytes()),
+            ReadFrom::After(ref id) => id.write_redis_args(out),
+        }
+    }
+}
+
+/// The per-stream `id` argument for `XREADGROUP`.
+///
+/// - `GroupReadFrom::Undelivered` (`>`) returns entries never delivered to
+///   any consumer of the group.
+/// - `GroupReadFrom::After(id)` returns the consumer's pending entries
+///   greater than `id`. Use `StreamEntryId::MIN` to start from the beginning (`0`).
+///
+/// `$` isn't valid for `XREADGROUP`, so there's no `New` variant:
+///
+/// ```compile_fail
+/// use redis_streams::{client_open,ReadFrom,StreamCommands,StreamReadOptions,StreamReadReply};
+/// let client = client_open("redis://127.0.0.1/0").unwrap();
+/// let mut con = client.get_connection().unwrap();
+/// let opts = StreamReadOptions::default().group("g1", "c1");
+/// let reply: StreamReadReply = con.xread_options(&["k1"], &[ReadFrom::New], opts).unwrap();
+/// ```
+///
+#[derive(PartialEq, Eq, Clone, Debug, Copy)]
+pub enum GroupReadFrom {
+    Undelivered,
+    After(StreamEntryId),
+}
+
+impl From<StreamEntryId> for GroupReadFrom {
+    fn from(id: StreamEntryId) -> Self {
+        GroupReadFrom::After(id)
+    }
+}
+
+impl ToRedisArgs for GroupReadFrom {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        match *self {
+            GroupReadFrom::Undelivered => out.write_arg(">".as_bytes()),
+            GroupReadFrom::After(ref id) => id.write_redis_args(out),
+        }
+    }
+}
+
+/// The `start` and `end` arguments for `XRANGE`, `XREVRANGE` and `XPENDING` commands.
+///
+/// `RangeBound::Min` (`-`) and `RangeBound::Max` (`+`) are the
+/// smallest and largest ids in the stream. `RangeBound::Exclusive`
+/// (`(<id>`) requires Redis 6.2 or later.
+#[derive(PartialEq, Eq, Clone, Debug, Copy)]
+pub enum RangeBound {
+    Min,
+    Max,
+    Inclusive(StreamEntryId),
+    Exclusive(StreamEntryId),
+}
+
+impl From<StreamEntryId> for RangeBound {
+    fn from(id: StreamEntryId) -> Self {
+        RangeBound::Inclusive(id)
+    }
+}
+
+impl ToRedisArgs for RangeBound {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        match *self {
+            RangeBound::Min => out.write_arg("-".as_bytes()),
+            RangeBound::Max => out.write_arg("+".as_bytes()),
+            RangeBound::Inclusive(ref id) => id.write_redis_args(out),
+            RangeBound::Exclusive(ref id) => out.write_arg(format!("({}", id).as_bytes()),
+        }
+    }
+}
 
 // Stream Maxlen Enum
 
@@ -29,6 +325,150 @@ impl ToRedisArgs for StreamMaxlen {
     }
 }
 
+// Stream Trim Strategy
+
+/// Utility enum for the `=` (exact) or `~` (approximate)
+/// trimming modifier used by `StreamTrimStrategy`.
+///
+/// `ApproxLimit(count)` also sets `LIMIT <count>`, capping how many
+/// entries are evicted per call. Redis rejects `LIMIT` for exact trimming.
+#[derive(PartialEq, Eq, Clone, Debug, Copy)]
+pub enum StreamTrimmingMode {
+    Exact,
+    Approx,
+    ApproxLimit(usize),
+}
+
+#[derive(PartialEq, Eq, Clone, Debug, Copy)]
+enum StreamTrimThreshold {
+    MaxLen(usize),
+    MinId(StreamEntryId),
+}
+
+/// Utility type for passing `MAXLEN|MINID [= or ~] <threshold> [LIMIT <count>]`
+/// arguments into `xtrim` and the `XADD` commands.
+///
+/// `MAXLEN` keeps the stream at a fixed length while `MINID`
+/// evicts entries with ids lower than the threshold.
+///
+/// ```
+/// use redis_streams::{StreamTrimStrategy,StreamTrimmingMode,StreamEntryId};
+/// let trim = StreamTrimStrategy::maxlen(StreamTrimmingMode::ApproxLimit(100), 1000);
+/// let trim = StreamTrimStrategy::minid(StreamTrimmingMode::Exact, StreamEntryId::new(1000, 0));
+/// ```
+///
+#[derive(PartialEq, Eq, Clone, Debug, Copy)]
+pub struct StreamTrimStrategy {
+    mode: StreamTrimmingMode,
+    threshold: StreamTrimThreshold,
+}
+
+impl StreamTrimStrategy {
+    /// Trim the stream to `count` entries.
+    pub fn maxlen(mode: StreamTrimmingMode, count: usize) -> Self {
+        StreamTrimStrategy {
+            mode,
+            threshold: StreamTrimThreshold::MaxLen(count),
+        }
+    }
+
+    /// Trim entries with ids lower than `id`.
+    pub fn minid(mode: StreamTrimmingMode, id: StreamEntryId) -> Self {
+        StreamTrimStrategy {
+            mode,
+            threshold: StreamTrimThreshold::MinId(id),
+        }
+    }
+
+    /// Trim entries added more than `age` ago, based on the local clock
+    /// and the timestamp part of server generated ids.
+    pub fn minid_ago(mode: StreamTrimmingMode, age: Duration) -> Self {
+        let time = SystemTime::now().checked_sub(age).unwrap_or(UNIX_EPOCH);
+        StreamTrimStrategy::minid(mode, StreamEntryId::from(time))
+    }
+}
+
+impl From<StreamMaxlen> for StreamTrimStrategy {
+    fn from(maxlen: StreamMaxlen) -> Self {
+        match maxlen {
+            StreamMaxlen::Equals(v) => StreamTrimStrategy::maxlen(StreamTrimmingMode::Exact, v),
+            StreamMaxlen::Aprrox(v) => StreamTrimStrategy::maxlen(StreamTrimmingMode::Approx, v),
+        }
+    }
+}
+
+impl ToRedisArgs for StreamTrimStrategy {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        match self.threshold {
+            StreamTrimThreshold::MaxLen(_) => out.write_arg("MAXLEN".as_bytes()),
+            StreamTrimThreshold::MinId(_) => out.write_arg("MINID".as_bytes()),
+        }
+        match self.mode {
+            StreamTrimmingMode::Exact => out.write_arg("=".as_bytes()),
+            StreamTrimmingMode::Approx | StreamTrimmingMode::ApproxLimit(_) => {
+                out.write_arg("~".as_bytes())
+            }
+        }
+        match self.threshold {
+            StreamTrimThreshold::MaxLen(v) => v.write_redis_args(out),
+            StreamTrimThreshold::MinId(id) => id.write_redis_args(out),
+        }
+        if let StreamTrimmingMode::ApproxLimit(count) = self.mode {
+            out.write_arg("LIMIT".as_bytes());
+            out.write_arg(format!("{}", count).as_bytes());
+        }
+    }
+}
+
+/// Builder options for [`xadd_options`] command.
+///
+/// [`xadd_options`]: ./trait.StreamCommands.html#method.xadd_options
+///
+#[derive(Default, Debug, Clone, Copy)]
+pub struct StreamAddOptions {
+    /// Set NOMKSTREAM cmd arg.
+    nomkstream: bool,
+    /// Set MAXLEN|MINID [~|=] <threshold> [LIMIT <count>] cmd args.
+    trim: Option<StreamTrimStrategy>,
+    /// Set the <ID or *> cmd arg. Defaults to `*`.
+    id: Option<AddId>,
+}
+
+impl StreamAddOptions {
+    pub fn nomkstream(mut self) -> Self {
+        self.nomkstream = true;
+        self
+    }
+
+    pub fn trim<T: Into<StreamTrimStrategy>>(mut self, trim: T) -> Self {
+        self.trim = Some(trim.into());
+        self
+    }
+
+    pub fn id<ID: Into<AddId>>(mut self, id: ID) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+}
+
+impl ToRedisArgs for StreamAddOptions {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        if self.nomkstream {
+            out.write_arg("NOMKSTREAM".as_bytes());
+        }
+        if let Some(ref trim) = self.trim {
+            trim.write_redis_args(out);
+        }
+        self.id.unwrap_or(AddId::Auto).write_redis_args(out);
+    }
+}
+
 /// Builder options for [`xclaim_options`] command.
 ///
 /// [`xclaim_options`]: ./trait.StreamCommands.html#method.xclaim_options
@@ -101,24 +541,337 @@ impl ToRedisArgs for StreamClaimOptions {
     }
 }
 
-/// Builder options for [`xread_options`] command.
-///
-/// [`xread_options`]: ./trait.StreamCommands.html#method.xread_options
-///
-#[derive(Default, Debug)]
-pub struct StreamReadOptions {
-    /// Set the BLOCK <milliseconds> cmd arg.
-    block: Option<usize>,
-    /// Set the COUNT <count> cmd arg.
-    count: Option<usize>,
-    /// Set the GROUP <groupname> <consumername> cmd arg.
-    /// This option will toggle the cmd from XREAD to XREADGROUP.
-    group: Option<(Vec<Vec<u8>>, Vec<Vec<u8>>)>,
-}
-
-impl StreamReadOptions {
+/// Builder options for [`xautoclaim_options`] command.
+///
+/// [`xautoclaim_options`]: ./trait.StreamCommands.html#method.xautoclaim_options
+///
+#[derive(Default, Debug, Clone, Copy)]
+pub struct StreamAutoClaimOptions {
+    /// Set COUNT <count> cmd arg.
+    count: Option<usize>,
+    /// Set JUSTID cmd arg. Claimed entries are
+    /// returned without their field/values.
+    justid: bool,
+}
+
+impl StreamAutoClaimOptions {
+    pub fn count(mut self, n: usize) -> Self {
+        self.count = Some(n);
+        self
+    }
+
+    pub fn with_justid(mut self) -> Self {
+        self.justid = true;
+        self
+    }
+}
+
+impl ToRedisArgs for StreamAutoClaimOptions {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        if let Some(ref n) = self.count {
+            out.write_arg("COUNT".as_bytes());
+            out.write_arg(format!("{}", n).as_bytes());
+        }
+        if self.justid {
+            out.write_arg("JUSTID".as_bytes());
+        }
+    }
+}
+
+/// Builder options for [`xgroup_create_options`] command.
+///
+/// Defaults to starting the group at `ReadFrom::New` (`$`).
+///
+/// [`xgroup_create_options`]: ./trait.StreamCommands.html#method.xgroup_create_options
+///
+#[derive(Debug, Clone, Copy)]
+pub struct StreamGroupCreateOptions {
+    /// Set the <id or $> cmd arg.
+    start: ReadFrom,
+    /// Set MKSTREAM cmd arg.
+    mkstream: bool,
+    /// Set ENTRIESREAD <entries_read> cmd arg (Redis 7.0 or later).
+    entries_read: Option<usize>,
+}
+
+impl Default for StreamGroupCreateOptions {
+    fn default() -> Self {
+        StreamGroupCreateOptions {
+            start: ReadFrom::New,
+            mkstream: false,
+            entries_read: None,
+        }
+    }
+}
+
+impl StreamGroupCreateOptions {
+    pub fn start<ID: Into<ReadFrom>>(mut self, id: ID) -> Self {
+        self.start = id.into();
+        self
+    }
+
+    pub fn mkstream(mut self) -> Self {
+        self.mkstream = true;
+        self
+    }
+
+    pub fn entries_read(mut self, n: usize) -> Self {
+        self.entries_read = Some(n);
+        self
+    }
+}
+
+impl ToRedisArgs for StreamGroupCreateOptions {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        self.start.write_redis_args(out);
+        if self.mkstream {
+            out.write_arg("MKSTREAM".as_bytes());
+        }
+        if let Some(ref n) = self.entries_read {
+            out.write_arg("ENTRIESREAD".as_bytes());
+            out.write_arg(format!("{}", n).as_bytes());
+        }
+    }
+}
+
+/// Builder options for [`xpending_options`] command.
+///
+/// Defaults to the whole stream (`-` to `+`) with a `COUNT` of 10.
+///
+/// [`xpending_options`]: ./trait.StreamCommands.html#method.xpending_options
+///
+#[derive(Debug, Clone)]
+pub struct StreamPendingOptions {
+    /// Set IDLE <min-idle-time> cmd arg (Redis 6.2 or later).
+    idle: Option<usize>,
+    /// Set the <start> cmd arg.
+    start: RangeBound,
+    /// Set the <end> cmd arg.
+    end: RangeBound,
+    /// Set the <count> cmd arg.
+    count: usize,
+    /// Set the <consumer> cmd arg.
+    consumer: Option<Vec<Vec<u8>>>,
+}
+
+impl Default for StreamPendingOptions {
+    fn default() -> Self {
+        StreamPendingOptions {
+            idle: None,
+            start: RangeBound::Min,
+            end: RangeBound::Max,
+            count: 10,
+            consumer: None,
+        }
+    }
+}
+
+impl StreamPendingOptions {
+    pub fn idle(mut self, ms: usize) -> Self {
+        self.idle = Some(ms);
+        self
+    }
+
+    pub fn start<S: Into<RangeBound>>(mut self, start: S) -> Self {
+        self.start = start.into();
+        self
+    }
+
+    pub fn end<E: Into<RangeBound>>(mut self, end: E) -> Self {
+        self.end = end.into();
+        self
+    }
+
+    pub fn count(mut self, n: usize) -> Self {
+        self.count = n;
+        self
+    }
+
+    pub fn consumer<CN: ToRedisArgs>(mut self, consumer: CN) -> Self {
+        self.consumer = Some(ToRedisArgs::to_redis_args(&consumer));
+        self
+    }
+}
+
+impl ToRedisArgs for StreamPendingOptions {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        if let Some(ref ms) = self.idle {
+            out.write_arg("IDLE".as_bytes());
+            out.write_arg(format!("{}", ms).as_bytes());
+        }
+        self.start.write_redis_args(out);
+        self.end.write_redis_args(out);
+        out.write_arg(format!("{}", self.count).as_bytes());
+        if let Some(ref consumer) = self.consumer {
+            for i in consumer {
+                out.write_arg(i);
+            }
+        }
+    }
+}
+
+/// Builder options for [`xsetid_options`] command.
+///
+/// [`xsetid_options`]: ./trait.StreamCommands.html#method.xsetid_options
+///
+#[derive(Default, Debug, Clone, Copy)]
+pub struct StreamSetIdOptions {
+    /// Set ENTRIESADDED <entries_added> cmd arg.
+    entries_added: Option<usize>,
+    /// Set MAXDELETEDID <max_deleted_id> cmd arg.
+    max_deleted_id: Option<StreamEntryId>,
+}
+
+impl StreamSetIdOptions {
+    pub fn entries_added(mut self, n: usize) -> Self {
+        self.entries_added = Some(n);
+        self
+    }
+
+    pub fn max_deleted_id(mut self, id: StreamEntryId) -> Self {
+        self.max_deleted_id = Some(id);
+        self
+    }
+}
+
+impl ToRedisArgs for StreamSetIdOptions {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        if let Some(ref n) = self.entries_added {
+            out.write_arg("ENTRIESADDED".as_bytes());
+            out.write_arg(format!("{}", n).as_bytes());
+        }
+        if let Some(ref id) = self.max_deleted_id {
+            out.write_arg("MAXDELETEDID".as_bytes());
+            id.write_redis_args(out);
+        }
+    }
+}
+
+/// Builder options for [`xread_options`] command.
+///
+/// Reads with `XREAD` and takes [`ReadFrom`] ids, until [`group`] turns
+/// it into `StreamReadOptions<ReadGroup>`, which reads with `XREADGROUP`
+/// and takes [`GroupReadFrom`] ids.
+///
+/// [`xread_options`]: ./trait.StreamCommands.html#method.xread_options
+/// [`group`]: #method.group
+/// [`ReadFrom`]: ./enum.ReadFrom.html
+/// [`GroupReadFrom`]: ./enum.GroupReadFrom.html
+///
+#[derive(Debug)]
+pub struct StreamReadOptions<G = NoGroup> {
+    /// Set the BLOCK <milliseconds> cmd arg.
+    block: Option<usize>,
+    /// Set the COUNT <count> cmd arg.
+    count: Option<usize>,
+    /// Set the GROUP <groupname> <consumername> cmd arg.
+    group: G,
+    /// Set the NOACK cmd arg. Only sent along with GROUP,
+    /// since plain XREAD has nothing to ack.
+    noack: bool,
+}
+
+/// `StreamReadOptions` without a group, read with `XREAD`.
+#[derive(Default, Debug, Clone, Copy)]
+pub struct NoGroup;
+
+/// The group and consumer of `StreamReadOptions`, read with `XREADGROUP`.
+#[derive(Debug, Clone)]
+pub struct ReadGroup {
+    group: Vec<Vec<u8>>,
+    consumer: Vec<Vec<u8>>,
+}
+
+/// Picks the command and the id type of `StreamReadOptions`.
+/// Implemented by [`NoGroup`] and [`ReadGroup`].
+///
+/// [`NoGroup`]: ./struct.NoGroup.html
+/// [`ReadGroup`]: ./struct.ReadGroup.html
+///
+pub trait StreamReadMode: ToRedisArgs {
+    /// The per-stream id argument.
+    type Id: ToRedisArgs + Copy;
+    /// `XREAD` or `XREADGROUP`.
+    const COMMAND: &'static str;
+}
+
+impl StreamReadMode for NoGroup {
+    type Id = ReadFrom;
+    const COMMAND: &'static str = "XREAD";
+}
+
+impl StreamReadMode for ReadGroup {
+    type Id = GroupReadFrom;
+    const COMMAND: &'static str = "XREADGROUP";
+}
+
+impl ToRedisArgs for NoGroup {
+    fn write_redis_args<W>(&self, _out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+    }
+}
+
+impl ToRedisArgs for ReadGroup {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        out.write_arg("GROUP".as_bytes());
+        for i in &self.group {
+            out.write_arg(i);
+        }
+        for i in &self.consumer {
+            out.write_arg(i);
+        }
+    }
+}
+
+impl Default for StreamReadOptions {
+    fn default() -> Self {
+        StreamReadOptions {
+            block: None,
+            count: None,
+            group: NoGroup,
+            noack: false,
+        }
+    }
+}
+
+impl StreamReadOptions {
+    pub fn group<GN: ToRedisArgs, CN: ToRedisArgs>(
+        self,
+        group_name: GN,
+        consumer_name: CN,
+    ) -> StreamReadOptions<ReadGroup> {
+        StreamReadOptions {
+            block: self.block,
+            count: self.count,
+            group: ReadGroup {
+                group: ToRedisArgs::to_redis_args(&group_name),
+                consumer: ToRedisArgs::to_redis_args(&consumer_name),
+            },
+            noack: self.noack,
+        }
+    }
+}
+
+impl<G: StreamReadMode> StreamReadOptions<G> {
     pub fn read_only(&self) -> bool {
-        self.group.is_none()
+        G::COMMAND == "XREAD"
     }
 
     pub fn block(mut self, ms: usize) -> Self {
@@ -131,20 +884,15 @@ impl StreamReadOptions {
         self
     }
 
-    pub fn group<GN: ToRedisArgs, CN: ToRedisArgs>(
-        mut self,
-        group_name: GN,
-        consumer_name: CN,
-    ) -> Self {
-        self.group = Some((
-            ToRedisArgs::to_redis_args(&group_name),
-            ToRedisArgs::to_redis_args(&consumer_name),
-        ));
+    /// Don't add the entries read to the group's pending entries list,
+    /// so they never need to be acked. This is ignored without `group`.
+    pub fn noack(mut self) -> Self {
+        self.noack = true;
         self
     }
 }
 
-impl ToRedisArgs for StreamReadOptions {
+impl<G: StreamReadMode> ToRedisArgs for StreamReadOptions<G> {
     fn write_redis_args<W>(&self, out: &mut W)
     where
         W: ?Sized + RedisWrite,
@@ -159,14 +907,9 @@ impl ToRedisArgs for StreamReadOptions {
             out.write_arg(format!("{}", n).as_bytes());
         }
 
-        if let Some(ref group) = self.group {
-            out.write_arg("GROUP".as_bytes());
-            for i in &group.0 {
-                out.write_arg(i);
-            }
-            for i in &group.1 {
-                out.write_arg(i);
-            }
+        self.group.write_redis_args(out);
+        if self.noack && !self.read_only() {
+            out.write_arg("NOACK".as_bytes());
         }
     }
 }
@@ -176,9 +919,52 @@ impl ToRedisArgs for StreamReadOptions {
 /// [`xread`]: ./trait.StreamCommands.html#method.xread
 /// [`xread_options`]: ./trait.StreamCommands.html#method.xread_options
 ///
-#[derive(Default, Debug, Clone)]
-pub struct StreamReadReply {
-    pub keys: Vec<StreamKey>,
+#[derive(Debug, Clone)]
+pub struct StreamReadReply<K = String, F = String> {
+    pub keys: Vec<StreamKey<K, F>>,
+}
+
+impl<K, F> Default for StreamReadReply<K, F> {
+    fn default() -> Self {
+        StreamReadReply { keys: Vec::new() }
+    }
+}
+
+/// Reply type used with [`xread_bytes`] or [`xread_options_bytes`], which
+/// keeps the keys and field names as bytes.
+///
+/// [`xread_bytes`]: ./trait.StreamCommands.html#method.xread_bytes
+/// [`xread_options_bytes`]: ./trait.StreamCommands.html#method.xread_options_bytes
+///
+pub type StreamReadBytesReply = StreamReadReply<Vec<u8>, Vec<u8>>;
+
+/// Reply type used with [`xread_typed`] or [`xread_typed_options`] commands.
+///
+/// The entries of every key read, in order, each one converted
+/// with `FromStreamEntry`.
+///
+/// [`xread_typed`]: ./trait.StreamCommands.html#method.xread_typed
+/// [`xread_typed_options`]: ./trait.StreamCommands.html#method.xread_typed_options
+///
+#[derive(Debug, Clone)]
+pub struct StreamTypedReply<T> {
+    pub entries: Vec<StreamTypedEntry<T>>,
+}
+
+impl<T> Default for StreamTypedReply<T> {
+    fn default() -> Self {
+        StreamTypedReply {
+            entries: Vec::new(),
+        }
+    }
+}
+
+/// A stream entry converted to `T`, along with the key it was read from.
+#[derive(Debug, Clone)]
+pub struct StreamTypedEntry<T> {
+    pub key: String,
+    pub id: StreamEntryId,
+    pub value: T,
 }
 
 /// Reply type used with [`xrange`], [`xrange_count`], [`xrange_all`], [`xrevrange`], [`xrevrange_count`], [`xrevrange_all`] commands.
@@ -190,18 +976,59 @@ pub struct StreamReadReply {
 /// [`xrevrange_count`]: ./trait.StreamCommands.html#method.xrevrange_count
 /// [`xrevrange_all`]: ./trait.StreamCommands.html#method.xrevrange_all
 ///
-#[derive(Default, Debug, Clone)]
-pub struct StreamRangeReply {
-    pub ids: Vec<StreamId>,
+#[derive(Debug, Clone)]
+pub struct StreamRangeReply<F = String> {
+    pub ids: Vec<StreamId<F>>,
+}
+
+impl<F> Default for StreamRangeReply<F> {
+    fn default() -> Self {
+        StreamRangeReply { ids: Vec::new() }
+    }
 }
 
 /// Reply type used with [`xclaim`] command.
 ///
 /// [`xclaim`]: ./trait.StreamCommands.html#method.xclaim
 ///
-#[derive(Default, Debug, Clone)]
-pub struct StreamClaimReply {
-    pub ids: Vec<StreamId>,
+#[derive(Debug, Clone)]
+pub struct StreamClaimReply<F = String> {
+    pub ids: Vec<StreamId<F>>,
+}
+
+impl<F> Default for StreamClaimReply<F> {
+    fn default() -> Self {
+        StreamClaimReply { ids: Vec::new() }
+    }
+}
+
+/// Reply type used with [`xautoclaim`] and [`xautoclaim_options`] commands.
+///
+/// Pass `next_cursor` as the `start` of the next call to continue scanning
+/// the pending entries list. A `next_cursor` of `0-0` means the scan is complete.
+/// `deleted_ids` lists pending ids which no longer exist in the stream
+/// (only reported by Redis 7 or later).
+///
+/// When called with `JUSTID`, the `claimed` entries have an empty `map`.
+///
+/// [`xautoclaim`]: ./trait.StreamCommands.html#method.xautoclaim
+/// [`xautoclaim_options`]: ./trait.StreamCommands.html#method.xautoclaim_options
+///
+#[derive(Debug, Clone)]
+pub struct StreamAutoClaimReply<F = String> {
+    pub next_cursor: StreamEntryId,
+    pub claimed: Vec<StreamId<F>>,
+    pub deleted_ids: Vec<StreamEntryId>,
+}
+
+impl<F> Default for StreamAutoClaimReply<F> {
+    fn default() -> Self {
+        StreamAutoClaimReply {
+            next_cursor: StreamEntryId::default(),
+            claimed: Vec::new(),
+            deleted_ids: Vec::new(),
+        }
+    }
 }
 
 /// Reply type used with [`xpending`] command.
@@ -233,8 +1060,8 @@ impl StreamPendingReply {
 #[derive(Default, Debug, Clone)]
 pub struct StreamPendingData {
     pub count: usize,
-    pub start_id: String,
-    pub end_id: String,
+    pub start_id: StreamEntryId,
+    pub end_id: StreamEntryId,
     pub consumers: Vec<StreamInfoConsumer>,
 }
 
@@ -255,12 +1082,88 @@ pub struct StreamPendingCountReply {
 ///
 #[derive(Default, Debug, Clone)]
 pub struct StreamInfoStreamReply {
-    pub last_generated_id: String,
+    pub last_generated_id: StreamEntryId,
     pub radix_tree_keys: usize,
     pub groups: usize,
     pub length: usize,
     pub first_entry: StreamId,
     pub last_entry: StreamId,
+    /// Redis 7.0 or later.
+    pub max_deleted_entry_id: Option<StreamEntryId>,
+    /// Redis 7.0 or later.
+    pub entries_added: Option<usize>,
+    /// Redis 7.0 or later.
+    pub recorded_first_entry_id: Option<StreamEntryId>,
+}
+
+/// Reply type used with [`xinfo_stream_full`] command.
+///
+/// [`xinfo_stream_full`]: ./trait.StreamCommands.html#method.xinfo_stream_full
+///
+#[derive(Default, Debug, Clone)]
+pub struct StreamInfoStreamFullReply {
+    pub length: usize,
+    pub radix_tree_keys: usize,
+    pub radix_tree_nodes: usize,
+    pub last_generated_id: StreamEntryId,
+    /// Redis 7.0 or later.
+    pub max_deleted_entry_id: Option<StreamEntryId>,
+    /// Redis 7.0 or later.
+    pub entries_added: Option<usize>,
+    /// Redis 7.0 or later.
+    pub recorded_first_entry_id: Option<StreamEntryId>,
+    pub entries: Vec<StreamId>,
+    pub groups: Vec<StreamInfoFullGroup>,
+}
+
+/// A group parsed from [`xinfo_stream_full`] command.
+///
+/// [`xinfo_stream_full`]: ./trait.StreamCommands.html#method.xinfo_stream_full
+///
+#[derive(Default, Debug, Clone)]
+pub struct StreamInfoFullGroup {
+    pub name: String,
+    pub last_delivered_id: StreamEntryId,
+    /// Redis 7.0 or later.
+    pub entries_read: Option<usize>,
+    /// Redis 7.0 or later. `None` as well when
+    /// the server can't compute the lag.
+    pub lag: Option<usize>,
+    pub pel_count: usize,
+    pub pel: Vec<StreamInfoFullPendingId>,
+    pub consumers: Vec<StreamInfoFullConsumer>,
+}
+
+/// A consumer parsed from [`xinfo_stream_full`] command.
+///
+/// [`xinfo_stream_full`]: ./trait.StreamCommands.html#method.xinfo_stream_full
+///
+#[derive(Default, Debug, Clone)]
+pub struct StreamInfoFullConsumer {
+    pub name: String,
+    /// Unix time in ms of the last attempted interaction.
+    pub seen_time: u64,
+    /// Unix time in ms of the last successful interaction.
+    /// Redis 7.2 or later.
+    pub active_time: Option<u64>,
+    pub pel_count: usize,
+    pub pel: Vec<StreamInfoFullPendingId>,
+}
+
+/// A pending message parsed from [`xinfo_stream_full`] command.
+///
+/// For the pending messages of a consumer, `consumer` is filled
+/// in with the name of that consumer.
+///
+/// [`xinfo_stream_full`]: ./trait.StreamCommands.html#method.xinfo_stream_full
+///
+#[derive(Default, Debug, Clone)]
+pub struct StreamInfoFullPendingId {
+    pub id: StreamEntryId,
+    pub consumer: String,
+    /// Unix time in ms of the last delivery.
+    pub delivery_time: u64,
+    pub times_delivered: usize,
 }
 
 /// Reply type used with [`xinfo_consumer`] command.
@@ -290,6 +1193,9 @@ pub struct StreamInfoConsumer {
     pub name: String,
     pub pending: usize,
     pub idle: usize,
+    /// Ms since the last successful interaction, or -1 if there
+    /// never was one. Redis 7.2 or later.
+    pub inactive: Option<i64>,
 }
 
 /// A group parsed from [`xinfo_groups`] command.
@@ -301,39 +1207,72 @@ pub struct StreamInfoGroup {
     pub name: String,
     pub consumers: usize,
     pub pending: usize,
-    pub last_delivered_id: String,
+    pub last_delivered_id: StreamEntryId,
+    /// Redis 7.0 or later.
+    pub entries_read: Option<usize>,
+    /// Redis 7.0 or later. `None` as well when
+    /// the server can't compute the lag.
+    pub lag: Option<usize>,
 }
 
 /// Represents a pending message parsed from `xpending` methods.
 #[derive(Default, Debug, Clone)]
 pub struct StreamPendingId {
-    pub id: String,
+    pub id: StreamEntryId,
     pub consumer: String,
-    pub last_delivered_ms: usize,
+    /// Time since the message was last delivered.
+    pub idle: Duration,
     pub times_delivered: usize,
 }
 
 /// Represents a stream `key` and its `id`'s parsed from `xread` methods.
-#[derive(Default, Debug, Clone)]
-pub struct StreamKey {
-    pub key: String,
-    pub ids: Vec<StreamId>,
+///
+/// `K` and `F` are the types of the key and field names. Use
+/// `Vec<u8>` for keys or fields which aren't valid UTF-8.
+#[derive(Debug, Clone)]
+pub struct StreamKey<K = String, F = String> {
+    pub key: K,
+    pub ids: Vec<StreamId<F>>,
 }
 
-impl StreamKey {
-    pub fn just_ids(&self) -> Vec<&String> {
-        self.ids.iter().map(|msg| &msg.id).collect::<Vec<&String>>()
+impl<K: Default, F> Default for StreamKey<K, F> {
+    fn default() -> Self {
+        StreamKey {
+            key: K::default(),
+            ids: Vec::new(),
+        }
     }
 }
 
-/// Represents a stream `id` and its field/values as a `HashMap`
-#[derive(Default, Debug, Clone)]
-pub struct StreamId {
-    pub id: String,
-    pub map: HashMap<String, Value>,
+impl<K, F> StreamKey<K, F> {
+    pub fn just_ids(&self) -> Vec<StreamEntryId> {
+        self.ids
+            .iter()
+            .map(|msg| msg.id)
+            .collect::<Vec<StreamEntryId>>()
+    }
+}
+
+/// Represents a stream `id` and its field/values as `StreamFields`.
+///
+/// `F` is the type of the field names. Use `Vec<u8>`
+/// for fields which aren't valid UTF-8.
+#[derive(Debug, Clone)]
+pub struct StreamId<F = String> {
+    pub id: StreamEntryId,
+    pub map: StreamFields<F>,
+}
+
+impl<F> Default for StreamId<F> {
+    fn default() -> Self {
+        StreamId {
+            id: StreamEntryId::default(),
+            map: StreamFields::default(),
+        }
+    }
 }
 
-impl StreamId {
+impl<F: FromRedisValue> StreamId<F> {
     pub fn from_bulk_value(v: &Value) -> RedisResult<Self> {
         let mut stream_id = StreamId::default();
         match *v {
@@ -350,7 +1289,9 @@ impl StreamId {
 
         Ok(stream_id)
     }
+}
 
+impl<F: AsRef<[u8]>> StreamId<F> {
     pub fn get<T: FromRedisValue>(&self, key: &str) -> Option<T> {
         match self.find(&key) {
             Some(ref x) => from_redis_value(*x).ok(),
@@ -365,63 +1306,267 @@ impl StreamId {
     pub fn contains_key(&self, key: &&str) -> bool {
         self.find(key).is_some()
     }
+}
 
+impl<F> StreamId<F> {
     pub fn len(&self) -> usize {
         self.map.len()
     }
 }
 
-impl FromRedisValue for StreamReadReply {
+/// The field/values of a stream entry, in the order they were added.
+///
+/// Redis keeps the fields of an entry in order and `XADD` allows the
+/// same field more than once, so both are kept here. Lookups by field
+/// return the first match. Writing these as args reproduces the entry,
+/// e.g. with `xadd_map`.
+///
+/// ```
+/// use redis::Value;
+/// use redis_streams::StreamFields;
+/// let mut fields: StreamFields = StreamFields::new();
+/// fields.push("a", Value::Data(b"1".to_vec()));
+/// fields.push("b", Value::Data(b"2".to_vec()));
+/// fields.push("a", Value::Data(b"3".to_vec()));
+/// assert_eq!(fields.get("a"), Some(&Value::Data(b"1".to_vec())));
+/// assert_eq!(fields.get_all("a").count(), 2);
+/// assert_eq!(fields.keys().collect::<Vec<_>>(), vec!["a", "b", "a"]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct StreamFields<F = String>(Vec<(F, Value)>);
+
+impl<F> Default for StreamFields<F> {
+    fn default() -> Self {
+        StreamFields(Vec::new())
+    }
+}
+
+impl<F> StreamFields<F> {
+    pub fn new() -> Self {
+        StreamFields(Vec::new())
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn push<I: Into<F>>(&mut self, field: I, value: Value) {
+        self.0.push((field.into(), value));
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, (F, Value)> {
+        self.0.iter()
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &F> {
+        self.0.iter().map(|(f, _)| f)
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &Value> {
+        self.0.iter().map(|(_, v)| v)
+    }
+
+    pub fn into_inner(self) -> Vec<(F, Value)> {
+        self.0
+    }
+}
+
+impl<F: AsRef<[u8]>> StreamFields<F> {
+    /// The value of the first `field` with this name.
+    pub fn get<Q: AsRef<[u8]> + ?Sized>(&self, field: &Q) -> Option<&Value> {
+        self.0
+            .iter()
+            .find(|(f, _)| f.as_ref() == field.as_ref())
+            .map(|(_, v)| v)
+    }
+
+    /// All the values of `field`, in order.
+    pub fn get_all<'a, Q: AsRef<[u8]> + ?Sized>(
+        &'a self,
+        field: &'a Q,
+    ) -> impl Iterator<Item = &'a Value> + 'a {
+        self.0
+            .iter()
+            .filter(move |(f, _)| f.as_ref() == field.as_ref())
+            .map(|(_, v)| v)
+    }
+
+    pub fn contains_key<Q: AsRef<[u8]> + ?Sized>(&self, field: &Q) -> bool {
+        self.get(field).is_some()
+    }
+}
+
+impl<F: Clone + Eq + Hash> StreamFields<F> {
+    /// Collect into a `HashMap`. For duplicate fields, the last value wins.
+    pub fn to_map(&self) -> HashMap<F, Value> {
+        self.0.iter().cloned().collect()
+    }
+}
+
+impl<F> From<Vec<(F, Value)>> for StreamFields<F> {
+    fn from(fields: Vec<(F, Value)>) -> Self {
+        StreamFields(fields)
+    }
+}
+
+impl<F> IntoIterator for StreamFields<F> {
+    type Item = (F, Value);
+    type IntoIter = std::vec::IntoIter<(F, Value)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, F> IntoIterator for &'a StreamFields<F> {
+    type Item = &'a (F, Value);
+    type IntoIter = std::slice::Iter<'a, (F, Value)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<F: FromRedisValue> FromRedisValue for StreamFields<F> {
     fn from_redis_value(v: &Value) -> RedisResult<Self> {
-        let rows: Vec<HashMap<String, Vec<HashMap<String, HashMap<String, Value>>>>> =
-            from_redis_value(v)?;
-        let mut reply = StreamReadReply::default();
-        for row in &rows {
-            for (key, entry) in row.iter() {
-                let mut k = StreamKey::default();
-                k.key = key.to_owned();
-                for id_row in entry {
-                    let mut i = StreamId::default();
-                    for (id, map) in id_row.iter() {
-                        i.id = id.to_owned();
-                        i.map = map.to_owned();
-                    }
-                    k.ids.push(i);
+        match *v {
+            // XREADGROUP returns nil fields for pending entries deleted from the stream
+            Value::Nil => Ok(StreamFields::new()),
+            Value::Bulk(ref items) if items.len() % 2 == 0 => {
+                let mut fields = StreamFields::new();
+                for pair in items.chunks(2) {
+                    fields.push(from_redis_value::<F>(&pair[0])?, pair[1].clone());
                 }
-                reply.keys.push(k);
+                Ok(fields)
             }
+            _ => Err(RedisError::from((
+                redis::ErrorKind::TypeError,
+                "Response type not stream fields compatible",
+            ))),
+        }
+    }
+}
+
+impl<F: ToRedisArgs> ToRedisArgs for StreamFields<F> {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        for (field, value) in &self.0 {
+            field.write_redis_args(out);
+            // entry values are always bulk strings in replies
+            match *value {
+                Value::Data(ref bytes) => out.write_arg(bytes),
+                Value::Int(n) => out.write_arg(format!("{}", n).as_bytes()),
+                Value::Status(ref status) => out.write_arg(status.as_bytes()),
+                Value::Okay => out.write_arg("OK".as_bytes()),
+                Value::Nil | Value::Bulk(_) => out.write_arg(&[]),
+            }
+        }
+    }
+
+    fn is_single_arg(&self) -> bool {
+        false
+    }
+}
+
+impl<F: FromRedisValue> FromRedisValue for StreamId<F> {
+    fn from_redis_value(v: &Value) -> RedisResult<Self> {
+        // each entry is [id, [field, value, ...]]
+        match *v {
+            Value::Bulk(ref items) if items.len() == 2 => Ok(StreamId {
+                id: from_redis_value(&items[0])?,
+                map: from_redis_value(&items[1])?,
+            }),
+            _ => Err(RedisError::from((
+                redis::ErrorKind::TypeError,
+                "Response type not stream entry compatible",
+            ))),
         }
-        Ok(reply)
     }
 }
 
-impl FromRedisValue for StreamRangeReply {
+impl<K: FromRedisValue, F: FromRedisValue> FromRedisValue for StreamReadReply<K, F> {
     fn from_redis_value(v: &Value) -> RedisResult<Self> {
-        let rows: Vec<HashMap<String, HashMap<String, Value>>> = from_redis_value(v)?;
-        let mut reply = StreamRangeReply::default();
+        // each row is [key, [entry, ...]]
+        let rows: Vec<Value> = from_redis_value(v)?;
+        let mut keys = Vec::with_capacity(rows.len());
         for row in &rows {
-            let mut i = StreamId::default();
-            for (id, map) in row.iter() {
-                i.id = id.to_owned();
-                i.map = map.to_owned();
+            let (key, ids) = from_redis_value(row)?;
+            keys.push(StreamKey { key, ids });
+        }
+        Ok(StreamReadReply { keys })
+    }
+}
+
+impl<T: FromStreamEntry> FromRedisValue for StreamTypedReply<T> {
+    fn from_redis_value(v: &Value) -> RedisResult<Self> {
+        let reply: StreamReadReply<String, Vec<u8>> = from_redis_value(v)?;
+        let mut entries = Vec::new();
+        for stream_key in reply.keys {
+            for stream_id in stream_key.ids {
+                entries.push(StreamTypedEntry {
+                    key: stream_key.key.clone(),
+                    id: stream_id.id,
+                    value: T::from_stream_entry(&stream_id)?,
+                });
             }
-            reply.ids.push(i);
         }
-        Ok(reply)
+        Ok(StreamTypedReply { entries })
     }
 }
 
-impl FromRedisValue for StreamClaimReply {
+impl<F: FromRedisValue> FromRedisValue for StreamRangeReply<F> {
     fn from_redis_value(v: &Value) -> RedisResult<Self> {
-        let rows: Vec<HashMap<String, HashMap<String, Value>>> = from_redis_value(v)?;
-        let mut reply = StreamClaimReply::default();
-        for row in &rows {
-            let mut i = StreamId::default();
-            for (id, map) in row.iter() {
-                i.id = id.to_owned();
-                i.map = map.to_owned();
+        Ok(StreamRangeReply {
+            ids: from_redis_value(v)?,
+        })
+    }
+}
+
+impl<F: FromRedisValue> FromRedisValue for StreamClaimReply<F> {
+    fn from_redis_value(v: &Value) -> RedisResult<Self> {
+        Ok(StreamClaimReply {
+            ids: from_redis_value(v)?,
+        })
+    }
+}
+
+impl<F: FromRedisValue> FromRedisValue for StreamAutoClaimReply<F> {
+    fn from_redis_value(v: &Value) -> RedisResult<Self> {
+        let parts = match *v {
+            Value::Bulk(ref parts) if parts.len() >= 2 => parts,
+            _ => {
+                return Err(RedisError::from((
+                    redis::ErrorKind::TypeError,
+                    "Response type not XAUTOCLAIM compatible",
+                )))
+            }
+        };
+        let mut reply = StreamAutoClaimReply {
+            next_cursor: from_redis_value(&parts[0])?,
+            ..StreamAutoClaimReply::default()
+        };
+        if let Value::Bulk(ref entries) = parts[1] {
+            for entry in entries {
+                match *entry {
+                    // Redis 6.2 returns nil for entries deleted from the stream
+                    Value::Nil => {}
+                    // JUSTID only returns the ids
+                    Value::Data(_) | Value::Status(_) => reply.claimed.push(StreamId {
+                        id: from_redis_value(entry)?,
+                        ..StreamId::default()
+                    }),
+                    _ => reply.claimed.push(StreamId::from_bulk_value(entry)?),
+                }
             }
-            reply.ids.push(i);
+        }
+        if let Some(deleted) = parts.get(2) {
+            reply.deleted_ids = from_redis_value(deleted)?;
         }
         Ok(reply)
     }
@@ -429,7 +1574,12 @@ impl FromRedisValue for StreamClaimReply {
 
 impl FromRedisValue for StreamPendingReply {
     fn from_redis_value(v: &Value) -> RedisResult<Self> {
-        let parts: (usize, Option<String>, Option<String>, Vec<Vec<String>>) = from_redis_value(v)?;
+        let parts: (
+            usize,
+            Option<StreamEntryId>,
+            Option<StreamEntryId>,
+            Vec<Vec<String>>,
+        ) = from_redis_value(v)?;
         let count = parts.0.to_owned() as usize;
 
         if count == 0 {
@@ -471,17 +1621,40 @@ impl FromRedisValue for StreamPendingReply {
     }
 }
 
+impl FromRedisValue for StreamPendingId {
+    fn from_redis_value(v: &Value) -> RedisResult<Self> {
+        // each row is [id, consumer, idle ms, delivery count]
+        match *v {
+            Value::Bulk(ref items) if items.len() == 4 => Ok(StreamPendingId {
+                id: from_redis_value(&items[0])?,
+                consumer: from_redis_value(&items[1])?,
+                idle: Duration::from_millis(from_redis_value(&items[2])?),
+                times_delivered: from_redis_value(&items[3])?,
+            }),
+            _ => Err(RedisError::from((
+                redis::ErrorKind::TypeError,
+                "Response type not pending id compatible",
+            ))),
+        }
+    }
+}
+
 impl FromRedisValue for StreamPendingCountReply {
     fn from_redis_value(v: &Value) -> RedisResult<Self> {
-        let parts: Vec<Vec<(String, String, usize, usize)>> = from_redis_value(v)?;
         let mut reply = StreamPendingCountReply::default();
-        for row in &parts {
-            let mut p = StreamPendingId::default();
-            p.id = row[0].0.to_owned();
-            p.consumer = row[0].1.to_owned();
-            p.last_delivered_ms = row[0].2.to_owned();
-            p.times_delivered = row[0].3.to_owned();
-            reply.ids.push(p);
+        match *v {
+            Value::Nil => {}
+            Value::Bulk(ref rows) => {
+                for row in rows {
+                    reply.ids.push(from_redis_value(row)?);
+                }
+            }
+            _ => {
+                return Err(RedisError::from((
+                    redis::ErrorKind::TypeError,
+                    "Response type not pending count compatible",
+                )))
+            }
         }
         Ok(reply)
     }
@@ -509,10 +1682,129 @@ impl FromRedisValue for StreamInfoStreamReply {
         if let Some(v) = &map.get("last-entry") {
             reply.last_entry = StreamId::from_bulk_value(v)?;
         }
+        if let Some(v) = &map.get("max-deleted-entry-id") {
+            reply.max_deleted_entry_id = from_redis_value(v)?;
+        }
+        if let Some(v) = &map.get("entries-added") {
+            reply.entries_added = from_redis_value(v)?;
+        }
+        if let Some(v) = &map.get("recorded-first-entry-id") {
+            reply.recorded_first_entry_id = from_redis_value(v)?;
+        }
+        Ok(reply)
+    }
+}
+
+impl FromRedisValue for StreamInfoStreamFullReply {
+    fn from_redis_value(v: &Value) -> RedisResult<Self> {
+        let map: HashMap<String, Value> = from_redis_value(v)?;
+        let mut reply = StreamInfoStreamFullReply::default();
+        if let Some(v) = &map.get("length") {
+            reply.length = from_redis_value(v)?;
+        }
+        if let Some(v) = &map.get("radix-tree-keys") {
+            reply.radix_tree_keys = from_redis_value(v)?;
+        }
+        if let Some(v) = &map.get("radix-tree-nodes") {
+            reply.radix_tree_nodes = from_redis_value(v)?;
+        }
+        if let Some(v) = &map.get("last-generated-id") {
+            reply.last_generated_id = from_redis_value(v)?;
+        }
+        if let Some(v) = &map.get("max-deleted-entry-id") {
+            reply.max_deleted_entry_id = from_redis_value(v)?;
+        }
+        if let Some(v) = &map.get("entries-added") {
+            reply.entries_added = from_redis_value(v)?;
+        }
+        if let Some(v) = &map.get("recorded-first-entry-id") {
+            reply.recorded_first_entry_id = from_redis_value(v)?;
+        }
+        if let Some(v) = &map.get("entries") {
+            let entries: Vec<Value> = from_redis_value(v)?;
+            for entry in &entries {
+                reply.entries.push(StreamId::from_bulk_value(entry)?);
+            }
+        }
+        if let Some(v) = &map.get("groups") {
+            reply.groups = from_redis_value(v)?;
+        }
         Ok(reply)
     }
 }
 
+impl FromRedisValue for StreamInfoFullGroup {
+    fn from_redis_value(v: &Value) -> RedisResult<Self> {
+        let map: HashMap<String, Value> = from_redis_value(v)?;
+        let mut g = StreamInfoFullGroup::default();
+        if let Some(v) = &map.get("name") {
+            g.name = from_redis_value(v)?;
+        }
+        if let Some(v) = &map.get("last-delivered-id") {
+            g.last_delivered_id = from_redis_value(v)?;
+        }
+        if let Some(v) = &map.get("entries-read") {
+            g.entries_read = from_redis_value(v)?;
+        }
+        if let Some(v) = &map.get("lag") {
+            g.lag = from_redis_value(v)?;
+        }
+        if let Some(v) = &map.get("pel-count") {
+            g.pel_count = from_redis_value(v)?;
+        }
+        if let Some(v) = &map.get("pel") {
+            // each row is [id, consumer, delivery time, delivery count]
+            let rows: Vec<Value> = from_redis_value(v)?;
+            for row in &rows {
+                let (id, consumer, delivery_time, times_delivered) = from_redis_value(row)?;
+                g.pel.push(StreamInfoFullPendingId {
+                    id,
+                    consumer,
+                    delivery_time,
+                    times_delivered,
+                });
+            }
+        }
+        if let Some(v) = &map.get("consumers") {
+            g.consumers = from_redis_value(v)?;
+        }
+        Ok(g)
+    }
+}
+
+impl FromRedisValue for StreamInfoFullConsumer {
+    fn from_redis_value(v: &Value) -> RedisResult<Self> {
+        let map: HashMap<String, Value> = from_redis_value(v)?;
+        let mut c = StreamInfoFullConsumer::default();
+        if let Some(v) = &map.get("name") {
+            c.name = from_redis_value(v)?;
+        }
+        if let Some(v) = &map.get("seen-time") {
+            c.seen_time = from_redis_value(v)?;
+        }
+        if let Some(v) = &map.get("active-time") {
+            c.active_time = from_redis_value(v)?;
+        }
+        if let Some(v) = &map.get("pel-count") {
+            c.pel_count = from_redis_value(v)?;
+        }
+        if let Some(v) = &map.get("pel") {
+            // each row is [id, delivery time, delivery count]
+            let rows: Vec<Value> = from_redis_value(v)?;
+            for row in &rows {
+                let (id, delivery_time, times_delivered) = from_redis_value(row)?;
+                c.pel.push(StreamInfoFullPendingId {
+                    id,
+                    consumer: c.name.clone(),
+                    delivery_time,
+                    times_delivered,
+                });
+            }
+        }
+        Ok(c)
+    }
+}
+
 impl FromRedisValue for StreamInfoConsumersReply {
     fn from_redis_value(v: &Value) -> RedisResult<Self> {
         let consumers: Vec<HashMap<String, Value>> = from_redis_value(v)?;
@@ -528,6 +1820,9 @@ impl FromRedisValue for StreamInfoConsumersReply {
             if let Some(v) = &map.get("idle") {
                 c.idle = from_redis_value(v)?;
             }
+            if let Some(v) = &map.get("inactive") {
+                c.inactive = from_redis_value(v)?;
+            }
             reply.consumers.push(c);
         }
 
@@ -553,6 +1848,12 @@ impl FromRedisValue for StreamInfoGroupsReply {
             if let Some(v) = &map.get("last-delivered-id") {
                 g.last_delivered_id = from_redis_value(v)?;
             }
+            if let Some(v) = &map.get("entries-read") {
+                g.entries_read = from_redis_value(v)?;
+            }
+            if let Some(v) = &map.get("lag") {
+                g.lag = from_redis_value(v)?;
+            }
             reply.groups.push(g);
         }
         Ok(reply)
@@ -1,5 +1,5 @@
 // This file is mostly a direct copy of this file in redis-rs.
-// all the async code has been removed for now...
+// async connections are resolved with `wait()` instead of a runtime...
 // https://github.com/mitsuhiko/redis-rs/blob/master/tests/support/mod.rs
 
 #![allow(dead_code)]
@@ -7,6 +7,7 @@
 extern crate net2;
 extern crate rand;
 
+use futures::Future;
 use redis;
 
 use std::env;
@@ -156,6 +157,10 @@ impl TestContext {
         self.client.get_connection().unwrap()
     }
 
+    pub fn async_connection(&self) -> redis::aio::Connection {
+        self.client.get_async_connection().wait().unwrap()
+    }
+
     pub fn stop_server(&mut self) {
         self.server.stop();
     }
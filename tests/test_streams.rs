@@ -1,18 +1,25 @@
+extern crate futures;
 extern crate redis;
 extern crate redis_streams;
 
+use futures::{Future, Stream};
 use redis::{Connection, RedisResult, ToRedisArgs};
 
 use redis_streams::{
-    StreamClaimOptions, StreamClaimReply, StreamCommands, StreamInfoConsumersReply,
-    StreamInfoGroupsReply, StreamInfoStreamReply, StreamMaxlen, StreamPendingCountReply,
-    StreamPendingReply, StreamRangeReply, StreamReadOptions, StreamReadReply,
+    AddId, AsyncStreamCommands, Backpressure, DeadLetterPolicy, GroupConsumer, GroupReadFrom,
+    IdempotentProducer, RangeBound, ReadFrom, StreamAddOptions, StreamAutoClaimOptions,
+    StreamAutoClaimReply, StreamClaimOptions, StreamClaimReply, StreamCommands, StreamEntryId,
+    StreamError, StreamGroupCreateOptions, StreamInfoConsumersReply, StreamInfoGroupsReply,
+    StreamInfoStreamFullReply, StreamInfoStreamReply, StreamMaxlen, StreamPendingCountReply,
+    StreamPendingOptions, StreamPendingReply, StreamPipelineExt, StreamPipelineReplies,
+    StreamProducer, StreamProducerOptions, StreamRangeReply, StreamReadBytesReply,
+    StreamReadOptions, StreamReadReply, StreamSetIdOptions, StreamTrimStrategy, StreamTrimmingMode,
 };
 
 use std::collections::BTreeMap;
 use std::str;
 use std::thread::sleep;
-use std::time::Duration;
+use std::time::{Duration, UNIX_EPOCH};
 
 use crate::support::*;
 
@@ -29,16 +36,21 @@ macro_rules! assert_args {
 }
 
 fn xadd(con: &mut Connection) {
+    let _: RedisResult<String> = con.xadd(
+        "k1",
+        StreamEntryId::new(1000, 0),
+        &[("hello", "world"), ("redis", "streams")],
+    );
+    let _: RedisResult<String> =
+        con.xadd("k1", StreamEntryId::new(1000, 1), &[("hello", "world2")]);
+    let _: RedisResult<String> = con.xadd("k2", StreamEntryId::new(2000, 0), &[("hello", "world")]);
     let _: RedisResult<String> =
-        con.xadd("k1", "1000-0", &[("hello", "world"), ("redis", "streams")]);
-    let _: RedisResult<String> = con.xadd("k1", "1000-1", &[("hello", "world2")]);
-    let _: RedisResult<String> = con.xadd("k2", "2000-0", &[("hello", "world")]);
-    let _: RedisResult<String> = con.xadd("k2", "2000-1", &[("hello", "world2")]);
+        con.xadd("k2", StreamEntryId::new(2000, 1), &[("hello", "world2")]);
 }
 
 fn xadd_keyrange(con: &mut Connection, key: &str, start: i32, end: i32) {
     for _i in start..end {
-        let _: RedisResult<String> = con.xadd(key, "*", &[("h", "w")]);
+        let _: RedisResult<String> = con.xadd(key, AddId::Auto, &[("h", "w")]);
     }
 }
 
@@ -46,8 +58,14 @@ fn xadd_keyrange(con: &mut Connection, key: &str, start: i32, end: i32) {
 fn test_cmd_options() {
     // Tests the following command option builders....
     // xclaim_options
+    // xautoclaim_options
+    // xadd_options
+    // xgroup_create_options
+    // xpending_options
     // xread_options
+    // xsetid_options
     // maxlen enum
+    // trim strategy
 
     // test read options
 
@@ -76,11 +94,93 @@ fn test_cmd_options() {
         "JUSTID"
     );
 
+    // test autoclaim options
+
+    let empty = StreamAutoClaimOptions::default();
+    assert_eq!(ToRedisArgs::to_redis_args(&empty).len(), 0);
+
+    let opts = StreamAutoClaimOptions::default().count(5).with_justid();
+    assert_args!(&opts, "COUNT", "5", "JUSTID");
+
+    // test add options
+
+    let opts = StreamAddOptions::default();
+    assert_args!(&opts, "*");
+
+    let opts = StreamAddOptions::default()
+        .nomkstream()
+        .trim(StreamMaxlen::Equals(10))
+        .id(StreamEntryId::new(1000, 0));
+    assert_args!(&opts, "NOMKSTREAM", "MAXLEN", "=", "10", "1000-0");
+
     // test maxlen options
 
     assert_args!(StreamMaxlen::Aprrox(10), "MAXLEN", "~", "10");
     assert_args!(StreamMaxlen::Equals(10), "MAXLEN", "=", "10");
 
+    // test trim strategies
+
+    assert_args!(
+        StreamTrimStrategy::from(StreamMaxlen::Aprrox(10)),
+        "MAXLEN",
+        "~",
+        "10"
+    );
+    assert_args!(
+        StreamTrimStrategy::maxlen(StreamTrimmingMode::ApproxLimit(5), 10),
+        "MAXLEN",
+        "~",
+        "10",
+        "LIMIT",
+        "5"
+    );
+    assert_args!(
+        StreamTrimStrategy::minid(StreamTrimmingMode::Exact, StreamEntryId::new(1000, 0)),
+        "MINID",
+        "=",
+        "1000-0"
+    );
+    assert_args!(
+        StreamTrimStrategy::minid(StreamTrimmingMode::Approx, StreamEntryId::new(1000, 0)),
+        "MINID",
+        "~",
+        "1000-0"
+    );
+
+    // test group create options
+
+    let opts = StreamGroupCreateOptions::default();
+    assert_args!(&opts, "$");
+
+    let opts = StreamGroupCreateOptions::default()
+        .start(StreamEntryId::new(1000, 0))
+        .mkstream()
+        .entries_read(3);
+    assert_args!(&opts, "1000-0", "MKSTREAM", "ENTRIESREAD", "3");
+
+    // test setid options
+
+    let empty = StreamSetIdOptions::default();
+    assert_eq!(ToRedisArgs::to_redis_args(&empty).len(), 0);
+
+    let opts = StreamSetIdOptions::default()
+        .entries_added(10)
+        .max_deleted_id(StreamEntryId::new(1000, 0));
+    assert_args!(&opts, "ENTRIESADDED", "10", "MAXDELETEDID", "1000-0");
+
+    // test pending options
+
+    let opts = StreamPendingOptions::default();
+    assert_args!(&opts, "-", "+", "10");
+
+    let opts = StreamPendingOptions::default()
+        .idle(30000)
+        .start(StreamEntryId::new(1000, 0))
+        .end(RangeBound::Exclusive(StreamEntryId::new(2000, 0)))
+        .count(5)
+        .consumer("c1");
+    assert_args!(&opts, "IDLE", "30000", "1000-0", "(2000-0", "5", "c1");
+
     // test read options
 
     let opts = StreamReadOptions::default()
@@ -98,6 +198,85 @@ fn test_cmd_options() {
         "group-name",
         "consumer-name"
     );
+
+    // noack is only sent w/ a group
+    let opts = StreamReadOptions::default().noack();
+    assert_eq!(ToRedisArgs::to_redis_args(&opts).len(), 0);
+
+    let opts = StreamReadOptions::default()
+        .noack()
+        .group("group-name", "consumer-name");
+    assert_args!(&opts, "GROUP", "group-name", "consumer-name", "NOACK");
+}
+
+#[test]
+fn test_stream_entry_id() {
+    // Tests the StreamEntryId type without a server....
+
+    let id: StreamEntryId = "1526919030474-55".parse().unwrap();
+    assert_eq!(id, StreamEntryId::new(1526919030474, 55));
+    assert_eq!(id.to_string(), "1526919030474-55");
+    assert_args!(id, "1526919030474-55");
+
+    // a bare timestamp means sequence 0
+    let id: StreamEntryId = "1000".parse().unwrap();
+    assert_eq!(id, StreamEntryId::from_millis(1000));
+
+    assert!("".parse::<StreamEntryId>().is_err());
+    assert!("abc-1".parse::<StreamEntryId>().is_err());
+    assert!("1000-".parse::<StreamEntryId>().is_err());
+    assert!("1000-1-1".parse::<StreamEntryId>().is_err());
+
+    // ids compare numerically, not lexically
+    let mut ids = vec![
+        StreamEntryId::new(10, 0),
+        StreamEntryId::new(9, 10),
+        StreamEntryId::new(9, 2),
+    ];
+    ids.sort();
+    assert_eq!(
+        ids,
+        vec![
+            StreamEntryId::new(9, 2),
+            StreamEntryId::new(9, 10),
+            StreamEntryId::new(10, 0)
+        ]
+    );
+
+    // next/prev roll over between sequence and timestamp
+    let id = StreamEntryId::new(5, u64::MAX);
+    assert_eq!(id.next(), Some(StreamEntryId::new(6, 0)));
+    assert_eq!(StreamEntryId::new(6, 0).prev(), Some(id));
+    assert_eq!(StreamEntryId::MIN.prev(), None);
+    assert_eq!(StreamEntryId::MAX.next(), None);
+
+    let time = UNIX_EPOCH + Duration::from_millis(1500);
+    assert_eq!(StreamEntryId::from(time), StreamEntryId::new(1500, 0));
+}
+
+#[test]
+fn test_id_args() {
+    // Tests the id argument enums....
+    // AddId
+    // ReadFrom
+    // GroupReadFrom
+    // RangeBound
+
+    let id = StreamEntryId::new(1000, 1);
+
+    assert_args!(AddId::Auto, "*");
+    assert_args!(AddId::from(id), "1000-1");
+
+    assert_args!(ReadFrom::New, "$");
+    assert_args!(ReadFrom::After(StreamEntryId::MIN), "0-0");
+
+    assert_args!(GroupReadFrom::Undelivered, ">");
+    assert_args!(GroupReadFrom::from(id), "1000-1");
+
+    assert_args!(RangeBound::Min, "-");
+    assert_args!(RangeBound::Max, "+");
+    assert_args!(RangeBound::from(id), "1000-1");
+    assert_args!(RangeBound::Exclusive(id), "(1000-1");
 }
 
 #[test]
@@ -115,11 +294,21 @@ fn test_assorted_1() {
     xadd(&mut con);
 
     // smoke test that we get the same id back
-    let result: RedisResult<String> = con.xadd("k0", "1000-0", &[("x", "y")]);
+    let result: RedisResult<String> = con.xadd("k0", StreamEntryId::new(1000, 0), &[("x", "y")]);
     assert_eq!(result.unwrap(), "1000-0");
 
+    // typed ids work as arguments and replies
+    let result: RedisResult<StreamEntryId> =
+        con.xadd("k0", StreamEntryId::new(1000, 1), &[("x", "y")]);
+    assert_eq!(result, Ok(StreamEntryId::new(1000, 1)));
+
     // xread reply
-    let reply: StreamReadReply = con.xread(&["k1", "k2", "k3"], &["0", "0", "0"]).unwrap();
+    let reply: StreamReadReply = con
+        .xread(
+            &["k1", "k2", "k3"],
+            &[ReadFrom::After(StreamEntryId::MIN); 3],
+        )
+        .unwrap();
 
     // verify reply contains 2 keys even though we asked for 3
     assert_eq!(&reply.keys.len(), &2usize);
@@ -127,7 +316,7 @@ fn test_assorted_1() {
     // verify first key & first id exist
     assert_eq!(&reply.keys[0].key, "k1");
     assert_eq!(&reply.keys[0].ids.len(), &2usize);
-    assert_eq!(&reply.keys[0].ids[0].id, "1000-0");
+    assert_eq!(reply.keys[0].ids[0].id, StreamEntryId::new(1000, 0));
 
     // lookup the key in StreamId map
     let hello: Option<String> = reply.keys[0].ids[0].get("hello");
@@ -136,14 +325,14 @@ fn test_assorted_1() {
     // verify the second key was written
     assert_eq!(&reply.keys[1].key, "k2");
     assert_eq!(&reply.keys[1].ids.len(), &2usize);
-    assert_eq!(&reply.keys[1].ids[0].id, "2000-0");
+    assert_eq!(reply.keys[1].ids[0].id, StreamEntryId::new(2000, 0));
 
     // test xadd_map
     let mut map: BTreeMap<&str, &str> = BTreeMap::new();
     map.insert("ab", "cd");
     map.insert("ef", "gh");
     map.insert("ij", "kl");
-    let _: RedisResult<String> = con.xadd_map("k3", "3000-0", map);
+    let _: RedisResult<String> = con.xadd_map("k3", StreamEntryId::new(3000, 0), map);
 
     let reply: StreamRangeReply = con.xrange_all("k3").unwrap();
     assert_eq!(reply.ids[0].contains_key(&"ab"), true);
@@ -161,7 +350,7 @@ fn test_assorted_1() {
 
     // test xadd_maxlen
     let _: RedisResult<String> =
-        con.xadd_maxlen("k4", StreamMaxlen::Equals(10), "*", &[("h", "w")]);
+        con.xadd_maxlen("k4", StreamMaxlen::Equals(10), AddId::Auto, &[("h", "w")]);
     let result: RedisResult<usize> = con.xlen("k4");
     assert_eq!(result, Ok(10));
 }
@@ -195,12 +384,12 @@ fn test_assorted_2() {
 
     // key should exist
     let reply: StreamInfoStreamReply = con.xinfo_stream("k1").unwrap();
-    assert_eq!(&reply.first_entry.id, "1000-0");
-    assert_eq!(&reply.last_entry.id, "1000-1");
-    assert_eq!(&reply.last_generated_id, "1000-1");
+    assert_eq!(reply.first_entry.id, StreamEntryId::new(1000, 0));
+    assert_eq!(reply.last_entry.id, StreamEntryId::new(1000, 1));
+    assert_eq!(reply.last_generated_id, StreamEntryId::new(1000, 1));
 
     // xgroup create (existing stream)
-    let result: RedisResult<String> = con.xgroup_create("k1", "g1", "$");
+    let result: RedisResult<String> = con.xgroup_create("k1", "g1", ReadFrom::New);
     assert_eq!(result.is_ok(), true);
 
     // xinfo groups (existing stream)
@@ -211,7 +400,8 @@ fn test_assorted_2() {
     assert_eq!(&reply.groups[0].name, &"g1");
 
     // test xgroup create w/ mkstream @ 0
-    let result: RedisResult<String> = con.xgroup_create_mkstream("k99", "g99", "0");
+    let result: RedisResult<String> =
+        con.xgroup_create_mkstream("k99", "g99", ReadFrom::After(StreamEntryId::MIN));
     assert_eq!(result.is_ok(), true);
 
     // Since nothing exists on this stream yet,
@@ -221,12 +411,20 @@ fn test_assorted_2() {
     let reply = result.unwrap();
     assert_eq!(&reply.groups.len(), &1);
     assert_eq!(&reply.groups[0].name, &"g99");
-    assert_eq!(&reply.groups[0].last_delivered_id, &"0-0");
+    assert_eq!(reply.groups[0].last_delivered_id, StreamEntryId::MIN);
 
     // call xadd on k99 just so we can read from it
     // using consumer g99 and test xinfo_consumers
-    let _: RedisResult<String> = con.xadd("k99", "1000-0", &[("a", "b"), ("c", "d")]);
-    let _: RedisResult<String> = con.xadd("k99", "1000-1", &[("e", "f"), ("g", "h")]);
+    let _: RedisResult<String> = con.xadd(
+        "k99",
+        StreamEntryId::new(1000, 0),
+        &[("a", "b"), ("c", "d")],
+    );
+    let _: RedisResult<String> = con.xadd(
+        "k99",
+        StreamEntryId::new(1000, 1),
+        &[("e", "f"), ("g", "h")],
+    );
 
     // test empty PEL
     let empty_reply: StreamPendingReply = con.xpending("k99", "g99").unwrap();
@@ -245,7 +443,7 @@ fn test_assorted_2() {
     let reply: StreamReadReply = con
         .xread_options(
             &["k99"],
-            &[">"],
+            &[GroupReadFrom::Undelivered],
             StreamReadOptions::default().group("g99", "c99"),
         )
         .unwrap();
@@ -264,7 +462,7 @@ fn test_assorted_2() {
     let reply: StreamReadReply = con
         .xread_options(
             &["k99"],
-            &["0"],
+            &[GroupReadFrom::After(StreamEntryId::MIN)],
             StreamReadOptions::default().group("g99", "c99"),
         )
         .unwrap();
@@ -275,12 +473,20 @@ fn test_assorted_2() {
     assert_eq!(reply.consumers[0].pending, 1);
 
     // add more and read so we can test xpending
-    let _: RedisResult<String> = con.xadd("k99", "1001-0", &[("i", "j"), ("k", "l")]);
-    let _: RedisResult<String> = con.xadd("k99", "1001-1", &[("m", "n"), ("o", "p")]);
+    let _: RedisResult<String> = con.xadd(
+        "k99",
+        StreamEntryId::new(1001, 0),
+        &[("i", "j"), ("k", "l")],
+    );
+    let _: RedisResult<String> = con.xadd(
+        "k99",
+        StreamEntryId::new(1001, 1),
+        &[("m", "n"), ("o", "p")],
+    );
     let _: StreamReadReply = con
         .xread_options(
             &["k99"],
-            &[">"],
+            &[GroupReadFrom::Undelivered],
             StreamReadOptions::default().group("g99", "c99"),
         )
         .unwrap();
@@ -292,8 +498,8 @@ fn test_assorted_2() {
     assert_eq!(data_reply.count(), 3);
 
     if let StreamPendingReply::Data(data) = data_reply {
-        assert_eq!(data.start_id, "1000-1");
-        assert_eq!(data.end_id, "1001-1");
+        assert_eq!(data.start_id, StreamEntryId::new(1000, 1));
+        assert_eq!(data.end_id, StreamEntryId::new(1001, 1));
         assert_eq!(data.consumers.len(), 1);
         assert_eq!(data.consumers[0].name, "c99");
     } else {
@@ -301,13 +507,460 @@ fn test_assorted_2() {
     }
 
     // both count variations have the same reply types
-    let reply: StreamPendingCountReply = con.xpending_count("k99", "g99", "-", "+", 10).unwrap();
+    let reply: StreamPendingCountReply = con
+        .xpending_count("k99", "g99", RangeBound::Min, RangeBound::Max, 10)
+        .unwrap();
     assert_eq!(reply.ids.len(), 3);
 
     let reply: StreamPendingCountReply = con
-        .xpending_consumer_count("k99", "g99", "-", "+", 10, "c99")
+        .xpending_consumer_count("k99", "g99", RangeBound::Min, RangeBound::Max, 10, "c99")
         .unwrap();
     assert_eq!(reply.ids.len(), 3);
+    assert_eq!(reply.ids[0].id, StreamEntryId::new(1000, 1));
+    assert_eq!(reply.ids[0].consumer, "c99");
+
+    let reply: StreamPendingCountReply = con
+        .xpending_idle_count("k99", "g99", 0, RangeBound::Min, RangeBound::Max, 10)
+        .unwrap();
+    assert_eq!(reply.ids.len(), 3);
+
+    let reply: StreamPendingCountReply = con
+        .xpending_idle_count("k99", "g99", 60000, RangeBound::Min, RangeBound::Max, 10)
+        .unwrap();
+    assert_eq!(reply.ids.len(), 0);
+
+    let opts = StreamPendingOptions::default()
+        .start(StreamEntryId::new(1000, 2))
+        .count(1)
+        .consumer("c99");
+    let reply = con.xpending_options("k99", "g99", opts).unwrap();
+    assert_eq!(reply.ids.len(), 1);
+    assert_eq!(reply.ids[0].id, StreamEntryId::new(1001, 0));
+
+    let opts = StreamPendingOptions::default().idle(60000).consumer("c99");
+    let reply = con.xpending_options("k99", "g99", opts).unwrap();
+    assert_eq!(reply.ids.len(), 0);
+}
+
+#[test]
+fn test_xpending_count_reply() {
+    // Tests parsing extended XPENDING replies without a server....
+    use redis::{FromRedisValue, Value};
+
+    let data = |s: &str| Value::Data(s.as_bytes().to_vec());
+
+    let value = Value::Bulk(vec![
+        Value::Bulk(vec![
+            data("1000-0"),
+            data("c1"),
+            Value::Int(1500),
+            Value::Int(1),
+        ]),
+        Value::Bulk(vec![
+            data("1000-1"),
+            data("c2"),
+            Value::Int(20),
+            Value::Int(3),
+        ]),
+    ]);
+    let reply = StreamPendingCountReply::from_redis_value(&value).unwrap();
+    assert_eq!(reply.ids.len(), 2);
+    assert_eq!(reply.ids[0].id, StreamEntryId::new(1000, 0));
+    assert_eq!(reply.ids[0].consumer, "c1");
+    assert_eq!(reply.ids[0].idle, Duration::from_millis(1500));
+    assert_eq!(reply.ids[0].times_delivered, 1);
+    assert_eq!(reply.ids[1].id, StreamEntryId::new(1000, 1));
+    assert_eq!(reply.ids[1].consumer, "c2");
+    assert_eq!(reply.ids[1].idle, Duration::from_millis(20));
+    assert_eq!(reply.ids[1].times_delivered, 3);
+
+    // nothing pending
+    let reply = StreamPendingCountReply::from_redis_value(&Value::Bulk(vec![])).unwrap();
+    assert_eq!(reply.ids.len(), 0);
+
+    // rows must have all four fields
+    let value = Value::Bulk(vec![Value::Bulk(vec![data("1000-0"), data("c1")])]);
+    assert!(StreamPendingCountReply::from_redis_value(&value).is_err());
+}
+
+#[test]
+fn test_xadd_options() {
+    // Tests the following commands....
+    // xadd_options
+    let ctx = TestContext::new();
+    let mut con = ctx.connection();
+
+    // NOMKSTREAM doesn't create the missing stream
+    let opts = StreamAddOptions::default().nomkstream();
+    let result = con.xadd_options("k1", &[("h", "w")], opts);
+    assert_eq!(result, Ok(None));
+    let result: RedisResult<usize> = con.xlen("k1");
+    assert_eq!(result, Ok(0));
+
+    // explicit ids are returned as-is
+    let opts = StreamAddOptions::default().id(StreamEntryId::new(1000, 0));
+    let result = con.xadd_options("k1", &[("h", "w")], opts);
+    assert_eq!(result, Ok(Some(StreamEntryId::new(1000, 0))));
+
+    // NOMKSTREAM w/ an existing stream and trimming
+    for _ in 0..10 {
+        let opts = StreamAddOptions::default()
+            .nomkstream()
+            .trim(StreamMaxlen::Equals(5));
+        let result = con.xadd_options("k1", &[("h", "w")], opts);
+        assert!(result.unwrap().unwrap() > StreamEntryId::new(1000, 0));
+    }
+    let result: RedisResult<usize> = con.xlen("k1");
+    assert_eq!(result, Ok(5));
+}
+
+#[test]
+fn test_async_commands() {
+    // Tests the following async commands....
+    // xadd
+    // xgroup_create_mkstream
+    // xread
+    // xread_options
+    // xack
+    // xpending
+    let ctx = TestContext::new();
+    let con = ctx.async_connection();
+
+    let (con, id): (_, StreamEntryId) = con
+        .xadd("k1", StreamEntryId::new(1000, 0), &[("h", "w")])
+        .wait()
+        .unwrap();
+    assert_eq!(id, StreamEntryId::new(1000, 0));
+
+    let (con, reply) =
+        AsyncStreamCommands::xread(con, &["k1"], &[ReadFrom::After(StreamEntryId::MIN)])
+            .wait()
+            .unwrap();
+    assert_eq!(reply.keys[0].ids[0].id, id);
+    assert_eq!(reply.keys[0].ids[0].get("h"), Some("w".to_string()));
+
+    let (con, _): (_, String) = con
+        .xgroup_create_mkstream("k1", "g1", ReadFrom::After(StreamEntryId::MIN))
+        .wait()
+        .unwrap();
+
+    let (con, reply) = con
+        .xread_options(
+            &["k1"],
+            &[GroupReadFrom::Undelivered],
+            StreamReadOptions::default().group("g1", "c1"),
+        )
+        .wait()
+        .unwrap();
+    assert_eq!(reply.keys[0].ids.len(), 1);
+
+    let (con, reply): (_, StreamPendingReply) = con.xpending("k1", "g1").wait().unwrap();
+    assert_eq!(reply.count(), 1);
+
+    let (_, acked): (_, usize) = con.xack("k1", "g1", &[id]).wait().unwrap();
+    assert_eq!(acked, 1);
+}
+
+#[test]
+fn test_xread_tail() {
+    // Tests the following commands....
+    // xread_iter
+    // xread_stream
+    let ctx = TestContext::new();
+    let mut con = ctx.connection();
+
+    xadd(&mut con);
+
+    let start = [ReadFrom::After(StreamEntryId::MIN); 2];
+
+    // tail both keys from the start
+    let mut tail = con.xread_iter(&["k1", "k2"], &start, 10, 1);
+    let id = tail.next().unwrap().unwrap();
+    assert_eq!(id.id, StreamEntryId::new(1000, 0));
+    assert_eq!(tail.last_key(), Some("k1"));
+    let ids: Vec<StreamEntryId> = tail.by_ref().take(3).map(|r| r.unwrap().id).collect();
+    assert_eq!(ids.len(), 3);
+
+    // positions have moved past everything read
+    let positions = tail.positions().to_vec();
+    assert_eq!(
+        positions,
+        vec![
+            ReadFrom::After(StreamEntryId::new(1000, 1)),
+            ReadFrom::After(StreamEntryId::new(2000, 1))
+        ]
+    );
+
+    // reading fewer entries than `count` doesn't skip the buffered ones
+    let mut tail = con.xread_iter(&["k1", "k2"], &start, 10, 10);
+    let id = tail.next().unwrap().unwrap();
+    assert_eq!(id.id, StreamEntryId::new(1000, 0));
+    let partial = tail.positions().to_vec();
+    assert_eq!(
+        partial,
+        vec![
+            ReadFrom::After(StreamEntryId::new(1000, 0)),
+            ReadFrom::After(StreamEntryId::MIN)
+        ]
+    );
+    let mut tail = con.xread_iter(&["k1", "k2"], &partial, 10, 10);
+    let ids: Vec<StreamEntryId> = tail.by_ref().take(3).map(|r| r.unwrap().id).collect();
+    assert_eq!(
+        ids,
+        vec![
+            StreamEntryId::new(1000, 1),
+            StreamEntryId::new(2000, 0),
+            StreamEntryId::new(2000, 1)
+        ]
+    );
+
+    // resume from the saved positions on a new connection
+    let mut con = ctx.connection();
+    let _: RedisResult<String> = con.xadd("k2", StreamEntryId::new(3000, 0), &[("h", "w")]);
+    let mut tail = con.xread_iter(&["k1", "k2"], &positions, 10, 10);
+    let id = tail.next().unwrap().unwrap();
+    assert_eq!(id.id, StreamEntryId::new(3000, 0));
+    assert_eq!(tail.last_key(), Some("k2"));
+
+    // mismatched keys and ids fail right away
+    let mut tail = con.xread_iter(&["k1", "k2"], &[ReadFrom::New], 10, 10);
+    assert!(tail.next().unwrap().is_err());
+
+    // async stream from the start of k1
+    let con = ctx.async_connection();
+    let ids: Vec<StreamEntryId> = con
+        .xread_stream(&["k1"], &start[..1], 10, 10)
+        .take(2)
+        .map(|id| id.id)
+        .collect()
+        .wait()
+        .unwrap();
+    assert_eq!(
+        ids,
+        vec![StreamEntryId::new(1000, 0), StreamEntryId::new(1000, 1)]
+    );
+}
+
+#[test]
+fn test_xread_noack() {
+    // Tests xread_options w/ NOACK....
+    let ctx = TestContext::new();
+    let mut con = ctx.connection();
+
+    xadd(&mut con);
+
+    let _: RedisResult<String> = con.xgroup_create("k1", "g1", StreamEntryId::MIN);
+    let reply: StreamReadReply = con
+        .xread_options(
+            &["k1"],
+            &[GroupReadFrom::Undelivered],
+            StreamReadOptions::default().group("g1", "c1").noack(),
+        )
+        .unwrap();
+    assert_eq!(reply.keys[0].ids.len(), 2);
+
+    // nothing was added to the PEL
+    let reply: StreamPendingReply = con.xpending("k1", "g1").unwrap();
+    assert_eq!(reply.count(), 0);
+
+    // ignored for a plain XREAD
+    let reply: StreamReadReply = con
+        .xread_options(
+            &["k1"],
+            &[ReadFrom::After(StreamEntryId::MIN)],
+            StreamReadOptions::default().noack(),
+        )
+        .unwrap();
+    assert_eq!(reply.keys[0].ids.len(), 2);
+}
+
+#[test]
+fn test_group_consumer() {
+    // Tests the GroupConsumer loop....
+    // creates the group, acks handled entries,
+    // retries failed ones and stops on shutdown
+    let ctx = TestContext::new();
+    let mut con = ctx.connection();
+
+    xadd(&mut con);
+
+    // fail the first entry and stop after the second
+    let consumer = GroupConsumer::new("k1", "g1", "c1").block(10);
+    let shutdown = consumer.shutdown_handle();
+    let mut seen = vec![];
+    let mut failed = vec![];
+    consumer
+        .run(
+            &mut con,
+            |entry| {
+                seen.push(entry.id);
+                if entry.id == StreamEntryId::new(1000, 0) {
+                    return Err("failed");
+                }
+                shutdown.shutdown();
+                Ok(())
+            },
+            |entry, e| failed.push((entry.id, e)),
+        )
+        .unwrap();
+    assert_eq!(
+        seen,
+        vec![StreamEntryId::new(1000, 0), StreamEntryId::new(1000, 1)]
+    );
+    assert_eq!(failed, vec![(StreamEntryId::new(1000, 0), "failed")]);
+    let reply: StreamPendingReply = con.xpending("k1", "g1").unwrap();
+    assert_eq!(reply.count(), 1);
+
+    // the group already exists and the failed entry is read first
+    let consumer = GroupConsumer::new("k1", "g1", "c1").block(10);
+    let shutdown = consumer.shutdown_handle();
+    let mut seen = vec![];
+    consumer
+        .run(
+            &mut con,
+            |entry| {
+                seen.push(entry.id);
+                shutdown.shutdown();
+                Ok::<(), ()>(())
+            },
+            |_, _| panic!("no entry fails"),
+        )
+        .unwrap();
+    assert_eq!(seen, vec![StreamEntryId::new(1000, 0)]);
+    let reply: StreamPendingReply = con.xpending("k1", "g1").unwrap();
+    assert_eq!(reply.count(), 0);
+
+    // a pending entry deleted from the stream is acked without the handler
+    let _: RedisResult<String> = con.xadd("k1", StreamEntryId::new(1000, 2), &[("h", "w")]);
+    let consumer = GroupConsumer::new("k1", "g1", "c1").block(10);
+    let shutdown = consumer.shutdown_handle();
+    consumer
+        .run(
+            &mut con,
+            |_| {
+                shutdown.shutdown();
+                Err(())
+            },
+            |_, _| {},
+        )
+        .unwrap();
+    let _: usize = con.xdel("k1", &[StreamEntryId::new(1000, 2)]).unwrap();
+    let _: RedisResult<String> = con.xadd("k1", StreamEntryId::new(1000, 3), &[("h", "w")]);
+    let consumer = GroupConsumer::new("k1", "g1", "c1").block(10);
+    let shutdown = consumer.shutdown_handle();
+    let mut seen = vec![];
+    consumer
+        .run(
+            &mut con,
+            |entry| {
+                seen.push(entry.id);
+                shutdown.shutdown();
+                Ok::<(), ()>(())
+            },
+            |_, _| panic!("no entry fails"),
+        )
+        .unwrap();
+    assert_eq!(seen, vec![StreamEntryId::new(1000, 3)]);
+    let reply: StreamPendingReply = con.xpending("k1", "g1").unwrap();
+    assert_eq!(reply.count(), 0);
+
+    // a shutdown requested before running returns right away
+    let consumer = GroupConsumer::new("k1", "g1", "c1").block(10);
+    consumer.shutdown_handle().shutdown();
+    consumer.run(&mut con, |_| Err(()), |_, _| {}).unwrap();
+}
+
+#[test]
+fn test_dead_letter() {
+    // Tests the DeadLetterPolicy....
+    // moves entries over the delivery limit
+    // to the dlq and acks them on the source
+    let ctx = TestContext::new();
+    let mut con = ctx.connection();
+
+    xadd(&mut con);
+
+    let _: RedisResult<String> = con.xgroup_create("k1", "g1", ReadFrom::After(StreamEntryId::MIN));
+    let _: StreamReadReply = con
+        .xread_options(
+            &["k1"],
+            &[GroupReadFrom::Undelivered],
+            StreamReadOptions::default().group("g1", "c1"),
+        )
+        .unwrap();
+
+    // bump the delivery count of the first entry only
+    let _: StreamClaimReply = con
+        .xclaim_options(
+            "k1",
+            "g1",
+            "c1",
+            0,
+            &[StreamEntryId::new(1000, 0)],
+            StreamClaimOptions::default().retry(5),
+        )
+        .unwrap();
+
+    let policy = DeadLetterPolicy::new("k1", "g1", "reaper", "k1:dlq", 3)
+        .count(1)
+        .reason("boom");
+    let moved = policy.run(&mut con).unwrap();
+    assert_eq!(moved, vec![StreamEntryId::new(1000, 0)]);
+
+    let reply: StreamPendingReply = con.xpending("k1", "g1").unwrap();
+    assert_eq!(reply.count(), 1);
+
+    let reply: StreamRangeReply = con.xrange_all("k1:dlq").unwrap();
+    assert_eq!(reply.ids.len(), 1);
+    let entry = &reply.ids[0];
+    assert_eq!(entry.get("hello"), Some("world".to_string()));
+    assert_eq!(entry.get("redis"), Some("streams".to_string()));
+    assert_eq!(
+        entry.get(DeadLetterPolicy::ID_FIELD),
+        Some("1000-0".to_string())
+    );
+    assert_eq!(
+        entry.get(DeadLetterPolicy::KEY_FIELD),
+        Some("k1".to_string())
+    );
+    assert_eq!(
+        entry.get(DeadLetterPolicy::GROUP_FIELD),
+        Some("g1".to_string())
+    );
+    assert_eq!(
+        entry.get(DeadLetterPolicy::REASON_FIELD),
+        Some("boom".to_string())
+    );
+    assert_eq!(
+        entry.get(DeadLetterPolicy::DELIVERIES_FIELD),
+        Some("6".to_string())
+    );
+
+    // nothing left over the limit
+    let moved = policy.run(&mut con).unwrap();
+    assert_eq!(moved.len(), 0);
+
+    // binary keys and groups
+    let key = b"k\xff".to_vec();
+    let group = b"g\xff".to_vec();
+    let _: RedisResult<String> = con.xadd(&key[..], StreamEntryId::new(1000, 0), &[("h", "w")]);
+    let _: RedisResult<String> =
+        con.xgroup_create(&key[..], &group[..], ReadFrom::After(StreamEntryId::MIN));
+    let _: StreamReadReply = con
+        .xread_options(
+            &[&key[..]],
+            &[GroupReadFrom::Undelivered],
+            StreamReadOptions::default()
+                .group(&group[..], "c1")
+                .count(1),
+        )
+        .unwrap();
+    let policy = DeadLetterPolicy::new(&key[..], &group[..], "reaper", "k2:dlq", 0);
+    let moved = policy.run(&mut con).unwrap();
+    assert_eq!(moved, vec![StreamEntryId::new(1000, 0)]);
+    let reply: StreamRangeReply = con.xrange_all("k2:dlq").unwrap();
+    assert_eq!(reply.ids[0].get(DeadLetterPolicy::KEY_FIELD), Some(key));
+    assert_eq!(reply.ids[0].get(DeadLetterPolicy::GROUP_FIELD), Some(group));
 }
 
 #[test]
@@ -320,7 +973,7 @@ fn test_xadd_maxlen_map() {
         let idx = i.to_string();
         map.insert("idx", &idx);
         let _: RedisResult<String> =
-            con.xadd_maxlen_map("maxlen_map", StreamMaxlen::Equals(3), "*", map);
+            con.xadd_maxlen_map("maxlen_map", StreamMaxlen::Equals(3), AddId::Auto, map);
     }
 
     let result: RedisResult<usize> = con.xlen("maxlen_map");
@@ -349,7 +1002,7 @@ fn test_xclaim() {
     //    past the idle time and read them from a different consumer
 
     // create the group
-    let result: RedisResult<String> = con.xgroup_create_mkstream("k1", "g1", "$");
+    let result: RedisResult<String> = con.xgroup_create_mkstream("k1", "g1", ReadFrom::New);
     assert_eq!(result.is_ok(), true);
 
     // add some keys
@@ -359,7 +1012,7 @@ fn test_xclaim() {
     let reply: StreamReadReply = con
         .xread_options(
             &["k1"],
-            &[">"],
+            &[GroupReadFrom::Undelivered],
             StreamReadOptions::default().group("g1", "c1"),
         )
         .unwrap();
@@ -375,9 +1028,7 @@ fn test_xclaim() {
     sleep(Duration::from_millis(5));
 
     // grab this id if > 4ms
-    let reply: StreamClaimReply = con
-        .xclaim("k1", "g1", "c2", 4, &[claim.id.clone()])
-        .unwrap();
+    let reply: StreamClaimReply = con.xclaim("k1", "g1", "c2", 4, &[claim.id]).unwrap();
     assert_eq!(reply.ids.len(), 1);
     assert_eq!(reply.ids[0].id, claim.id);
 
@@ -402,7 +1053,7 @@ fn test_xclaim() {
             "g1",
             "c3",
             4,
-            &[claim.id.clone()],
+            &[claim.id],
             StreamClaimOptions::default().with_force(),
         )
         .unwrap();
@@ -433,6 +1084,595 @@ fn test_xclaim() {
     assert_eq!(claimed.len(), 10);
 }
 
+#[test]
+fn test_xautoclaim() {
+    // Tests the following commands....
+    // xautoclaim
+    // xautoclaim_options
+    // xautoclaim_iter
+    let ctx = TestContext::new();
+    let mut con = ctx.connection();
+
+    let result: RedisResult<String> = con.xgroup_create_mkstream("k1", "g1", ReadFrom::New);
+    assert!(result.is_ok());
+
+    xadd_keyrange(&mut con, "k1", 0, 10);
+
+    // check out all 10 ids to c1 without acking them
+    let reply: StreamReadReply = con
+        .xread_options(
+            &["k1"],
+            &[GroupReadFrom::Undelivered],
+            StreamReadOptions::default().group("g1", "c1"),
+        )
+        .unwrap();
+    let ids = reply.keys[0].just_ids();
+    assert_eq!(ids.len(), 10);
+
+    // nothing has been idle for a minute yet
+    let reply = con
+        .xautoclaim("k1", "g1", "c2", 60000, StreamEntryId::MIN)
+        .unwrap();
+    assert_eq!(reply.claimed.len(), 0);
+    assert_eq!(reply.next_cursor, StreamEntryId::MIN);
+
+    sleep(Duration::from_millis(5));
+
+    // claim the first 4 and make sure the cursor points at the 5th
+    let reply = con
+        .xautoclaim_options(
+            "k1",
+            "g1",
+            "c2",
+            4,
+            StreamEntryId::MIN,
+            StreamAutoClaimOptions::default().count(4),
+        )
+        .unwrap();
+    assert_eq!(reply.claimed.len(), 4);
+    assert_eq!(reply.claimed[0].id, ids[0]);
+    assert_eq!(reply.claimed[0].get("h"), Some("w".to_string()));
+    assert_eq!(reply.next_cursor, ids[4]);
+
+    sleep(Duration::from_millis(5));
+
+    // walk the rest of the pending entries list for c3
+    let claimed: Vec<StreamEntryId> = con
+        .xautoclaim_iter(
+            "k1",
+            "g1",
+            "c3",
+            4,
+            StreamAutoClaimOptions::default().count(3).with_justid(),
+        )
+        .map(|r| r.unwrap().id)
+        .collect();
+    assert_eq!(claimed, ids);
+}
+
+#[test]
+fn test_xautoclaim_reply() {
+    // Tests parsing XAUTOCLAIM replies without a server....
+    use redis::{FromRedisValue, Value};
+
+    let data = |s: &str| Value::Data(s.as_bytes().to_vec());
+
+    // Redis 7 reply w/ deleted ids
+    let value = Value::Bulk(vec![
+        data("1000-2"),
+        Value::Bulk(vec![Value::Bulk(vec![
+            data("1000-0"),
+            Value::Bulk(vec![data("h"), data("w")]),
+        ])]),
+        Value::Bulk(vec![data("1000-1")]),
+    ]);
+    let reply: StreamAutoClaimReply = StreamAutoClaimReply::from_redis_value(&value).unwrap();
+    assert_eq!(reply.next_cursor, StreamEntryId::new(1000, 2));
+    assert_eq!(reply.claimed.len(), 1);
+    assert_eq!(reply.claimed[0].get("h"), Some("w".to_string()));
+    assert_eq!(reply.deleted_ids, vec![StreamEntryId::new(1000, 1)]);
+
+    // Redis 6.2 JUSTID reply w/ a deleted entry
+    let value = Value::Bulk(vec![
+        data("0-0"),
+        Value::Bulk(vec![data("1000-0"), Value::Nil]),
+    ]);
+    let reply: StreamAutoClaimReply = StreamAutoClaimReply::from_redis_value(&value).unwrap();
+    assert_eq!(reply.next_cursor, StreamEntryId::MIN);
+    assert_eq!(reply.claimed.len(), 1);
+    assert_eq!(reply.claimed[0].id, StreamEntryId::new(1000, 0));
+    assert_eq!(reply.deleted_ids.len(), 0);
+}
+
+#[test]
+fn test_xinfo_stream_full() {
+    // Tests the following commands....
+    // xinfo_stream_full
+    // xinfo_stream_full_count
+    let ctx = TestContext::new();
+    let mut con = ctx.connection();
+
+    xadd(&mut con);
+
+    let _: RedisResult<String> = con.xgroup_create("k1", "g1", ReadFrom::After(StreamEntryId::MIN));
+    let _: StreamReadReply = con
+        .xread_options(
+            &["k1"],
+            &[GroupReadFrom::Undelivered],
+            StreamReadOptions::default().group("g1", "c1").count(1),
+        )
+        .unwrap();
+
+    let reply = con.xinfo_stream_full("k1").unwrap();
+    assert_eq!(reply.length, 2);
+    assert_eq!(reply.last_generated_id, StreamEntryId::new(1000, 1));
+    assert_eq!(reply.entries.len(), 2);
+    assert_eq!(reply.entries[0].get("hello"), Some("world".to_string()));
+    assert_eq!(reply.groups.len(), 1);
+
+    let group = &reply.groups[0];
+    assert_eq!(group.name, "g1");
+    assert_eq!(group.last_delivered_id, StreamEntryId::new(1000, 0));
+    assert_eq!(group.pel_count, 1);
+    assert_eq!(group.pel[0].id, StreamEntryId::new(1000, 0));
+    assert_eq!(group.pel[0].consumer, "c1");
+    assert_eq!(group.pel[0].times_delivered, 1);
+    assert_eq!(group.consumers.len(), 1);
+    assert_eq!(group.consumers[0].name, "c1");
+    assert_eq!(group.consumers[0].pel_count, 1);
+    assert_eq!(group.consumers[0].pel[0].consumer, "c1");
+
+    let reply = con.xinfo_stream_full_count("k1", 1).unwrap();
+    assert_eq!(reply.entries.len(), 1);
+}
+
+#[test]
+fn test_xinfo_stream_full_reply() {
+    // Tests parsing XINFO STREAM FULL replies without a server....
+    use redis::{FromRedisValue, Value};
+
+    let data = |s: &str| Value::Data(s.as_bytes().to_vec());
+
+    let group_pel = Value::Bulk(vec![
+        Value::Bulk(vec![
+            data("1000-0"),
+            data("c1"),
+            Value::Int(1600000000000),
+            Value::Int(2),
+        ]),
+        Value::Bulk(vec![
+            data("1000-1"),
+            data("c2"),
+            Value::Int(1600000000001),
+            Value::Int(1),
+        ]),
+    ]);
+    let consumer = Value::Bulk(vec![
+        data("name"),
+        data("c1"),
+        data("seen-time"),
+        Value::Int(1600000000000),
+        data("pel-count"),
+        Value::Int(1),
+        data("pel"),
+        Value::Bulk(vec![Value::Bulk(vec![
+            data("1000-0"),
+            Value::Int(1600000000000),
+            Value::Int(2),
+        ])]),
+    ]);
+    let group = Value::Bulk(vec![
+        data("name"),
+        data("g1"),
+        data("last-delivered-id"),
+        data("1000-1"),
+        data("pel-count"),
+        Value::Int(2),
+        data("pel"),
+        group_pel,
+        data("consumers"),
+        Value::Bulk(vec![consumer]),
+    ]);
+    let value = Value::Bulk(vec![
+        data("length"),
+        Value::Int(2),
+        data("radix-tree-keys"),
+        Value::Int(1),
+        data("radix-tree-nodes"),
+        Value::Int(2),
+        data("last-generated-id"),
+        data("1000-1"),
+        data("entries"),
+        Value::Bulk(vec![Value::Bulk(vec![
+            data("1000-0"),
+            Value::Bulk(vec![data("h"), data("w")]),
+        ])]),
+        data("groups"),
+        Value::Bulk(vec![group]),
+    ]);
+
+    let reply = StreamInfoStreamFullReply::from_redis_value(&value).unwrap();
+    assert_eq!(reply.length, 2);
+    assert_eq!(reply.radix_tree_keys, 1);
+    assert_eq!(reply.radix_tree_nodes, 2);
+    assert_eq!(reply.last_generated_id, StreamEntryId::new(1000, 1));
+    assert_eq!(reply.entries.len(), 1);
+    assert_eq!(reply.entries[0].get("h"), Some("w".to_string()));
+
+    let group = &reply.groups[0];
+    assert_eq!(group.name, "g1");
+    assert_eq!(group.pel_count, 2);
+    assert_eq!(group.pel.len(), 2);
+    assert_eq!(group.pel[1].id, StreamEntryId::new(1000, 1));
+    assert_eq!(group.pel[1].consumer, "c2");
+    assert_eq!(group.pel[1].delivery_time, 1600000000001);
+    assert_eq!(group.pel[0].times_delivered, 2);
+
+    let consumer = &group.consumers[0];
+    assert_eq!(consumer.name, "c1");
+    assert_eq!(consumer.seen_time, 1600000000000);
+    assert_eq!(consumer.pel.len(), 1);
+    assert_eq!(consumer.pel[0].id, StreamEntryId::new(1000, 0));
+    assert_eq!(consumer.pel[0].consumer, "c1");
+    assert_eq!(consumer.pel[0].times_delivered, 2);
+}
+
+#[test]
+fn test_xinfo_reply_fields() {
+    // Tests parsing XINFO replies from older and newer servers....
+    use redis::{FromRedisValue, Value};
+
+    let data = |s: &str| Value::Data(s.as_bytes().to_vec());
+
+    // Redis 5 replies
+    let value = Value::Bulk(vec![
+        data("length"),
+        Value::Int(2),
+        data("last-generated-id"),
+        data("1000-1"),
+    ]);
+    let reply = StreamInfoStreamReply::from_redis_value(&value).unwrap();
+    assert_eq!(reply.length, 2);
+    assert_eq!(reply.max_deleted_entry_id, None);
+    assert_eq!(reply.entries_added, None);
+    assert_eq!(reply.recorded_first_entry_id, None);
+
+    let value = Value::Bulk(vec![Value::Bulk(vec![
+        data("name"),
+        data("g1"),
+        data("last-delivered-id"),
+        data("1000-0"),
+    ])]);
+    let reply = StreamInfoGroupsReply::from_redis_value(&value).unwrap();
+    assert_eq!(reply.groups[0].entries_read, None);
+    assert_eq!(reply.groups[0].lag, None);
+
+    // Redis 7 replies
+    let value = Value::Bulk(vec![
+        data("length"),
+        Value::Int(2),
+        data("last-generated-id"),
+        data("1000-1"),
+        data("max-deleted-entry-id"),
+        data("0-0"),
+        data("entries-added"),
+        Value::Int(2),
+        data("recorded-first-entry-id"),
+        data("1000-0"),
+    ]);
+    let reply = StreamInfoStreamReply::from_redis_value(&value).unwrap();
+    assert_eq!(reply.max_deleted_entry_id, Some(StreamEntryId::MIN));
+    assert_eq!(reply.entries_added, Some(2));
+    assert_eq!(
+        reply.recorded_first_entry_id,
+        Some(StreamEntryId::new(1000, 0))
+    );
+
+    let value = Value::Bulk(vec![
+        Value::Bulk(vec![
+            data("name"),
+            data("g1"),
+            data("entries-read"),
+            Value::Int(1),
+            data("lag"),
+            Value::Int(1),
+        ]),
+        // the lag can't always be computed
+        Value::Bulk(vec![
+            data("name"),
+            data("g2"),
+            data("entries-read"),
+            Value::Nil,
+            data("lag"),
+            Value::Nil,
+        ]),
+    ]);
+    let reply = StreamInfoGroupsReply::from_redis_value(&value).unwrap();
+    assert_eq!(reply.groups[0].entries_read, Some(1));
+    assert_eq!(reply.groups[0].lag, Some(1));
+    assert_eq!(reply.groups[1].entries_read, None);
+    assert_eq!(reply.groups[1].lag, None);
+
+    let value = Value::Bulk(vec![Value::Bulk(vec![
+        data("name"),
+        data("c1"),
+        data("idle"),
+        Value::Int(10),
+        data("inactive"),
+        Value::Int(-1),
+    ])]);
+    let reply = StreamInfoConsumersReply::from_redis_value(&value).unwrap();
+    assert_eq!(reply.consumers[0].idle, 10);
+    assert_eq!(reply.consumers[0].inactive, Some(-1));
+}
+
+#[test]
+fn test_stream_fields() {
+    // Tests field order and duplicate fields are kept....
+    let ctx = TestContext::new();
+    let mut con = ctx.connection();
+
+    let _: RedisResult<String> = con.xadd(
+        "k1",
+        StreamEntryId::new(1000, 0),
+        &[("b", "1"), ("a", "2"), ("b", "3")],
+    );
+
+    let reply: StreamRangeReply = con.xrange_all("k1").unwrap();
+    let entry = &reply.ids[0];
+    assert_eq!(entry.len(), 3);
+    assert_eq!(entry.map.keys().collect::<Vec<_>>(), vec!["b", "a", "b"]);
+    assert_eq!(entry.get("b"), Some("1".to_string()));
+    let values: Vec<String> = entry
+        .map
+        .get_all("b")
+        .map(|v| redis::from_redis_value(v).unwrap())
+        .collect();
+    assert_eq!(values, vec!["1", "3"]);
+
+    // re-publishing reproduces the entry
+    let _: RedisResult<String> = con.xadd_map("k2", StreamEntryId::new(1000, 0), entry.map.clone());
+    let copy: StreamRangeReply = con.xrange_all("k2").unwrap();
+    assert_eq!(copy.ids[0].map, entry.map);
+
+    // the last duplicate wins in a HashMap
+    let map = entry.map.to_map();
+    assert_eq!(map.len(), 2);
+    assert_eq!(
+        redis::from_redis_value::<String>(&map["b"]).unwrap(),
+        "3".to_string()
+    );
+}
+
+#[test]
+fn test_binary_reply() {
+    // Tests parsing non UTF-8 keys and fields....
+    use redis::{FromRedisValue, Value};
+
+    let key = vec![0xff, 0x01];
+    let field = vec![0xfe, 0x02];
+
+    let entry = Value::Bulk(vec![
+        Value::Data(b"1000-0".to_vec()),
+        Value::Bulk(vec![Value::Data(field.clone()), Value::Data(b"v".to_vec())]),
+    ]);
+    let value = Value::Bulk(vec![Value::Bulk(vec![
+        Value::Data(key.clone()),
+        Value::Bulk(vec![entry]),
+    ])]);
+
+    // String keys and fields can't hold these
+    assert!(StreamReadReply::<String, String>::from_redis_value(&value).is_err());
+
+    let reply = StreamReadBytesReply::from_redis_value(&value).unwrap();
+    assert_eq!(reply.keys[0].key, key);
+    let id = &reply.keys[0].ids[0];
+    assert_eq!(id.id, StreamEntryId::new(1000, 0));
+    assert_eq!(id.map.keys().collect::<Vec<_>>(), vec![&field]);
+    assert_eq!(id.map.get(&field), Some(&Value::Data(b"v".to_vec())));
+}
+
+#[test]
+fn test_binary_keys() {
+    // Tests reading non UTF-8 keys and fields....
+    // xrange_all
+    // xread
+    use redis::Value;
+
+    let key = vec![0xff, 0x01];
+    let field = vec![0xfe, 0x02];
+
+    let ctx = TestContext::new();
+    let mut con = ctx.connection();
+
+    let _: RedisResult<String> =
+        con.xadd(&key[..], StreamEntryId::new(1000, 0), &[(&field[..], "v")]);
+
+    let reply = con
+        .xrange_bytes(&key[..], RangeBound::Min, RangeBound::Max)
+        .unwrap();
+    assert_eq!(
+        reply.ids[0].map.get(&field),
+        Some(&Value::Data(b"v".to_vec()))
+    );
+
+    let reply = con
+        .xread_bytes(&[&key[..]], &[ReadFrom::After(StreamEntryId::MIN)])
+        .unwrap();
+    assert_eq!(reply.keys[0].key, key);
+    assert_eq!(reply.keys[0].ids[0].map.len(), 1);
+}
+
+#[cfg(feature = "derive")]
+mod derive_event {
+    use redis_streams::{FromStreamEntry, ToStreamEntry};
+
+    #[derive(Debug, PartialEq, FromStreamEntry, ToStreamEntry)]
+    pub struct Event {
+        #[stream(rename = "type")]
+        pub kind: String,
+        #[stream(default)]
+        pub retries: u32,
+        #[stream(optional)]
+        pub user: Option<String>,
+        pub payload: Vec<u8>,
+    }
+}
+
+#[test]
+#[cfg(feature = "derive")]
+fn test_stream_entry_derive() {
+    // Tests mapping entries to and from structs....
+    use crate::derive_event::Event;
+    use redis::{FromRedisValue, Value};
+    use redis_streams::{FromStreamEntry, StreamId, StreamTypedReply, ToStreamEntry};
+
+    let event = Event {
+        kind: "login".to_string(),
+        retries: 2,
+        user: None,
+        payload: vec![0xff, 0x00],
+    };
+    let fields = event.to_stream_entry().unwrap();
+    assert_eq!(
+        fields,
+        vec![
+            ("type".to_string(), b"login".to_vec()),
+            ("retries".to_string(), b"2".to_vec()),
+            ("payload".to_string(), vec![0xff, 0x00]),
+        ]
+    );
+
+    let entry = |fields: Vec<(&str, &[u8])>| {
+        Value::Bulk(vec![
+            Value::Data(b"1000-0".to_vec()),
+            Value::Bulk(
+                fields
+                    .into_iter()
+                    .flat_map(|(f, v)| vec![Value::Data(f.into()), Value::Data(v.into())])
+                    .collect(),
+            ),
+        ])
+    };
+
+    // round trip
+    let value = entry(
+        fields
+            .iter()
+            .map(|(f, v)| (&f[..], &v[..]))
+            .collect::<Vec<_>>(),
+    );
+    let stream_id: StreamId = StreamId::from_redis_value(&value).unwrap();
+    assert_eq!(Event::from_stream_entry(&stream_id).unwrap(), event);
+
+    // default and optional fields
+    let value = entry(vec![("type", b"x"), ("user", b"u1"), ("payload", b"")]);
+    let stream_id: StreamId = StreamId::from_redis_value(&value).unwrap();
+    let decoded = Event::from_stream_entry(&stream_id).unwrap();
+    assert_eq!(decoded.retries, 0);
+    assert_eq!(decoded.user, Some("u1".to_string()));
+
+    // missing and invalid fields
+    let value = entry(vec![("payload", b"")]);
+    let stream_id: StreamId = StreamId::from_redis_value(&value).unwrap();
+    let err = Event::from_stream_entry(&stream_id).unwrap_err();
+    assert!(err.to_string().contains("type"));
+
+    let value = entry(vec![("type", b"x"), ("retries", b"two"), ("payload", b"")]);
+    let stream_id: StreamId = StreamId::from_redis_value(&value).unwrap();
+    let err = Event::from_stream_entry(&stream_id).unwrap_err();
+    assert!(err.to_string().contains("retries"));
+
+    // xread reply
+    let value = Value::Bulk(vec![Value::Bulk(vec![
+        Value::Data(b"k1".to_vec()),
+        Value::Bulk(vec![entry(vec![("type", b"x"), ("payload", b"p")])]),
+    ])]);
+    let reply: StreamTypedReply<Event> = StreamTypedReply::from_redis_value(&value).unwrap();
+    assert_eq!(reply.entries.len(), 1);
+    assert_eq!(reply.entries[0].key, "k1");
+    assert_eq!(reply.entries[0].id, StreamEntryId::new(1000, 0));
+    assert_eq!(reply.entries[0].value.kind, "x");
+}
+
+#[test]
+#[cfg(feature = "derive")]
+fn test_stream_entry_derive_not_single_value() {
+    // Tests values which aren't a single bulk string....
+    use redis_streams::ToStreamEntry;
+
+    #[derive(ToStreamEntry)]
+    struct Tagged {
+        tags: Vec<String>,
+        user: Option<String>,
+    }
+
+    let tagged = Tagged {
+        tags: vec!["a".to_string(), "b".to_string()],
+        user: Some("u1".to_string()),
+    };
+    let err = tagged.to_stream_entry().unwrap_err();
+    assert!(err.to_string().contains("tags"));
+
+    // `None` without `#[stream(optional)]` has no value to write
+    let tagged = Tagged {
+        tags: vec!["a".to_string()],
+        user: None,
+    };
+    let err = tagged.to_stream_entry().unwrap_err();
+    assert!(err.to_string().contains("user"));
+
+    let tagged = Tagged {
+        tags: vec!["a".to_string()],
+        user: Some("u1".to_string()),
+    };
+    assert_eq!(tagged.to_stream_entry().unwrap().len(), 2);
+}
+
+#[test]
+#[cfg(feature = "derive")]
+fn test_xread_typed() {
+    // Tests adding and reading derived structs....
+    // xadd
+    // xread_typed
+    // xread_typed_options
+    use crate::derive_event::Event;
+    use redis_streams::ToStreamEntry;
+
+    let ctx = TestContext::new();
+    let mut con = ctx.connection();
+
+    let event = Event {
+        kind: "login".to_string(),
+        retries: 0,
+        user: Some("u1".to_string()),
+        payload: b"p".to_vec(),
+    };
+    let id: StreamEntryId = con
+        .xadd("k1", AddId::Auto, &event.to_stream_entry().unwrap())
+        .unwrap();
+
+    let reply = con
+        .xread_typed::<Event, _>(&["k1"], &[ReadFrom::After(StreamEntryId::MIN)])
+        .unwrap();
+    assert_eq!(reply.entries.len(), 1);
+    assert_eq!(reply.entries[0].id, id);
+    assert_eq!(reply.entries[0].value, event);
+
+    let _: RedisResult<String> = con.xgroup_create("k1", "g1", ReadFrom::After(StreamEntryId::MIN));
+    let opts = StreamReadOptions::default().group("g1", "c1");
+    let reply = con
+        .xread_typed_options::<Event, _, _>(&["k1"], &[GroupReadFrom::Undelivered], opts)
+        .unwrap();
+    assert_eq!(reply.entries[0].value, event);
+
+    // entries missing required fields fail the reply
+    let _: RedisResult<String> = con.xadd("k1", AddId::Auto, &[("user", "u2")]);
+    let reply: RedisResult<redis_streams::StreamTypedReply<Event>> =
+        con.xread_typed(&["k1"], &[ReadFrom::After(id)]);
+    assert!(reply.is_err());
+}
+
 #[test]
 fn test_xdel() {
     // Tests the following commands....
@@ -453,6 +1693,402 @@ fn test_xdel() {
     assert_eq!(result, Ok(2));
 }
 
+#[test]
+fn test_xsetid() {
+    // Tests the following commands....
+    // xsetid
+    // xsetid_options
+    let ctx = TestContext::new();
+    let mut con = ctx.connection();
+
+    xadd(&mut con);
+
+    let result: RedisResult<String> = con.xsetid("k1", StreamEntryId::new(5000, 0));
+    assert_eq!(result.is_ok(), true);
+    let reply: StreamInfoStreamReply = con.xinfo_stream("k1").unwrap();
+    assert_eq!(reply.last_generated_id, StreamEntryId::new(5000, 0));
+
+    // can't go below the last entry in the stream
+    let result: RedisResult<String> = con.xsetid("k1", StreamEntryId::new(1, 0));
+    assert_eq!(result.is_err(), true);
+
+    let opts = StreamSetIdOptions::default()
+        .entries_added(10)
+        .max_deleted_id(StreamEntryId::new(4000, 0));
+    let result: RedisResult<String> = con.xsetid_options("k1", StreamEntryId::new(6000, 0), opts);
+    assert_eq!(result.is_ok(), true);
+    let reply: StreamInfoStreamReply = con.xinfo_stream("k1").unwrap();
+    assert_eq!(reply.last_generated_id, StreamEntryId::new(6000, 0));
+    assert_eq!(reply.entries_added, Some(10));
+    assert_eq!(
+        reply.max_deleted_entry_id,
+        Some(StreamEntryId::new(4000, 0))
+    );
+}
+
+#[test]
+fn test_stream_errors() {
+    // Tests classifying stream errors....
+    // xgroup_create_if_missing
+    let ctx = TestContext::new();
+    let mut con = ctx.connection();
+
+    let classify = |result: RedisResult<String>| match StreamError::from(result.unwrap_err()) {
+        StreamError::GroupExists(_) => "group exists",
+        StreamError::NoGroup(_) => "no group",
+        StreamError::IdTooSmall(_) => "id too small",
+        StreamError::WrongType(_) => "wrong type",
+        StreamError::InvalidId(_) => "invalid id",
+        StreamError::Other(_) => "other",
+    };
+
+    // group/stream missing
+    let result = con.xgroup_create("k1", "g1", ReadFrom::New);
+    assert_eq!(classify(result), "no group");
+    let opts = StreamReadOptions::default().group("g1", "c1");
+    let result: RedisResult<StreamReadReply> =
+        con.xread_options(&["k1"], &[GroupReadFrom::Undelivered], opts);
+    assert_eq!(classify(result.map(|_| String::new())), "no group");
+
+    // group exists
+    let opts = StreamGroupCreateOptions::default().mkstream();
+    assert_eq!(con.xgroup_create_if_missing("k1", "g1", opts), Ok(true));
+    assert_eq!(con.xgroup_create_if_missing("k1", "g1", opts), Ok(false));
+    let result = con.xgroup_create("k1", "g1", ReadFrom::New);
+    assert_eq!(classify(result), "group exists");
+
+    // id too small
+    let _: String = con
+        .xadd("k1", StreamEntryId::new(1000, 0), &[("f", "v")])
+        .unwrap();
+    let result = con.xadd("k1", StreamEntryId::new(1000, 0), &[("f", "v")]);
+    assert_eq!(classify(result), "id too small");
+    let result = con.xsetid("k1", StreamEntryId::new(1, 0));
+    assert_eq!(classify(result), "id too small");
+
+    // invalid id
+    let result = redis::cmd("XADD")
+        .arg("k1")
+        .arg("not-an-id")
+        .arg("f")
+        .arg("v")
+        .query(&mut con);
+    assert_eq!(classify(result), "invalid id");
+
+    // wrong type
+    let _: () = redis::cmd("SET")
+        .arg("s1")
+        .arg("v")
+        .query(&mut con)
+        .unwrap();
+    let result: RedisResult<String> = con.xadd("s1", AddId::Auto, &[("f", "v")]);
+    let err = StreamError::from(result.unwrap_err());
+    assert_eq!(err.to_string().starts_with("WRONGTYPE"), true);
+
+    // converts back into the original error
+    let err: redis::RedisError = err.into();
+    assert_eq!(err.extension_error_code(), Some("WRONGTYPE"));
+
+    // any other error
+    let result = redis::cmd("XADD").arg("k1").query(&mut con);
+    assert_eq!(classify(result), "other");
+}
+
+#[test]
+fn test_xack_and_add() {
+    // Tests forwarding entries between streams....
+    // xack_and_add
+    // xack_and_add_multi
+    let ctx = TestContext::new();
+    let mut con = ctx.connection();
+
+    xadd(&mut con);
+
+    let _: RedisResult<String> = con.xgroup_create("k1", "g1", ReadFrom::After(StreamEntryId::MIN));
+    let opts = StreamReadOptions::default().group("g1", "c1");
+    let _: StreamReadReply = con
+        .xread_options(&["k1"], &[GroupReadFrom::Undelivered], opts)
+        .unwrap();
+
+    // acked and added
+    let id = StreamEntryId::new(1000, 0);
+    let forwarded = con
+        .xack_and_add("k1", "g1", id, "k3", &[("hello", "world")])
+        .unwrap();
+    assert_eq!(forwarded.is_some(), true);
+    let reply: StreamRangeReply = con.xrange_all("k3").unwrap();
+    assert_eq!(reply.ids.len(), 1);
+    assert_eq!(reply.ids[0].id, forwarded.unwrap());
+    assert_eq!(reply.ids[0].get("hello"), Some("world".to_string()));
+    let pending = con.xpending("k1", "g1").unwrap();
+    assert_eq!(pending.count(), 1);
+
+    // no longer pending, so nothing is added
+    let forwarded = con
+        .xack_and_add("k1", "g1", id, "k3", &[("hello", "world")])
+        .unwrap();
+    assert_eq!(forwarded, None);
+    let len: usize = con.xlen("k3").unwrap();
+    assert_eq!(len, 1);
+
+    // multi/exec fallback
+    let id = StreamEntryId::new(1000, 1);
+    let new_id = con
+        .xack_and_add_multi("k1", "g1", id, "k3", &[("hello", "world2")])
+        .unwrap();
+    let reply: StreamRangeReply = con.xrange_all("k3").unwrap();
+    assert_eq!(reply.ids.len(), 2);
+    assert_eq!(reply.ids[1].id, new_id);
+    let pending = con.xpending("k1", "g1").unwrap();
+    assert_eq!(pending.count(), 0);
+}
+
+#[test]
+fn test_stream_producer() {
+    // Tests batching entries with a producer....
+    let ctx = TestContext::new();
+    let mut con = ctx.connection();
+
+    let opts = StreamProducerOptions::default().batch_size(3).maxlen(4);
+    let producer = StreamProducer::start(ctx.connection(), opts);
+    let handles: Vec<_> = (0..10)
+        .map(|i| {
+            let key = if i % 2 == 0 { "k1" } else { "k2" };
+            producer.send(key, &[("i", i)]).unwrap()
+        })
+        .collect();
+    producer.flush();
+    let ids: Vec<StreamEntryId> = handles.into_iter().map(|h| h.wait().unwrap()).collect();
+
+    // ids per key are in the order the entries were sent
+    assert_eq!(ids[0] < ids[2], true);
+    assert_eq!(ids[7] < ids[9], true);
+
+    let reply: StreamRangeReply = con
+        .xrevrange_count("k1", RangeBound::Max, RangeBound::Min, 1)
+        .unwrap();
+    assert_eq!(reply.ids[0].id, ids[8]);
+    assert_eq!(reply.ids[0].get("i"), Some(8));
+
+    producer.close();
+
+    // queue full
+    let opts = StreamProducerOptions::default()
+        .linger(60000)
+        .capacity(2)
+        .backpressure(Backpressure::Error);
+    let producer = StreamProducer::start(ctx.connection(), opts);
+    let first = producer.send("k3", &[("i", 0)]).unwrap();
+    let _ = producer.send("k3", &[("i", 1)]).unwrap();
+    assert_eq!(producer.send("k3", &[("i", 2)]).is_err(), true);
+    // closing writes what's queued
+    producer.close();
+    assert_eq!(first.wait().is_ok(), true);
+    let len: usize = con.xlen("k3").unwrap();
+    assert_eq!(len, 2);
+
+    // drop oldest
+    let opts = StreamProducerOptions::default()
+        .linger(60000)
+        .capacity(2)
+        .backpressure(Backpressure::DropOldest);
+    let producer = StreamProducer::start(ctx.connection(), opts);
+    let first = producer.send("k4", &[("i", 0)]).unwrap();
+    let _ = producer.send("k4", &[("i", 1)]).unwrap();
+    let last = producer.send("k4", &[("i", 2)]).unwrap();
+    assert_eq!(first.wait().is_err(), true);
+    producer.flush();
+    let id = last.wait().unwrap();
+    let reply: StreamRangeReply = con.xrange_all("k4").unwrap();
+    assert_eq!(reply.ids.len(), 2);
+    assert_eq!(reply.ids[1].id, id);
+
+    // a single entry is written after `linger`, without a flush
+    let opts = StreamProducerOptions::default().linger(5);
+    let producer = StreamProducer::start(ctx.connection(), opts);
+    let handle = producer.send("k5", &[("i", 0)]).unwrap();
+    let id = handle.wait().unwrap();
+    let reply: StreamRangeReply = con.xrange_all("k5").unwrap();
+    assert_eq!(reply.ids[0].id, id);
+
+    // an entry the server rejects doesn't fail the rest of the flush
+    let _: () = redis::cmd("SET")
+        .arg("k6")
+        .arg("not a stream")
+        .query(&mut con)
+        .unwrap();
+    let bad = producer.send("k6", &[("i", 0)]).unwrap();
+    let good = producer.send("k5", &[("i", 1)]).unwrap();
+    producer.flush();
+    assert_eq!(bad.wait().is_err(), true);
+    assert_eq!(good.wait().is_ok(), true);
+    producer.close();
+
+    // flushing an empty queue doesn't flush the next entry early
+    let opts = StreamProducerOptions::default().linger(60000);
+    let producer = StreamProducer::start(ctx.connection(), opts);
+    producer.flush();
+    let _ = producer.send("k7", &[("i", 0)]).unwrap();
+    sleep(Duration::from_millis(50));
+    let len: usize = con.xlen("k7").unwrap();
+    assert_eq!(len, 0);
+    producer.close();
+}
+
+#[test]
+fn test_xadd_idempotent() {
+    // Tests publishing entries at most once....
+    // xadd_idempotent
+    let ctx = TestContext::new();
+    let mut con = ctx.connection();
+
+    let first = con
+        .xadd_idempotent("k1", "p1", 1, 60000, &[("hello", "world")])
+        .unwrap();
+    let again = con
+        .xadd_idempotent("k1", "p1", 1, 60000, &[("hello", "world")])
+        .unwrap();
+    assert_eq!(first, again);
+    let len: usize = con.xlen("k1").unwrap();
+    assert_eq!(len, 1);
+
+    // the producer id and seq are attached to the entry
+    let reply: StreamRangeReply = con.xrange_all("k1").unwrap();
+    let entry = &reply.ids[0];
+    assert_eq!(entry.get("hello"), Some("world".to_string()));
+    assert_eq!(
+        entry.get(IdempotentProducer::PRODUCER_ID_FIELD),
+        Some("p1".to_string())
+    );
+    assert_eq!(entry.get(IdempotentProducer::SEQ_FIELD), Some(1));
+
+    // another seq or producer is a new entry
+    let _ = con
+        .xadd_idempotent("k1", "p1", 2, 60000, &[("hello", "world")])
+        .unwrap();
+    let _ = con
+        .xadd_idempotent("k1", "p2", 1, 60000, &[("hello", "world")])
+        .unwrap();
+    let len: usize = con.xlen("k1").unwrap();
+    assert_eq!(len, 3);
+
+    // a ttl of 0 is rejected before the entry is added
+    let result = con.xadd_idempotent("k1", "p1", 3, 0, &[("hello", "world")]);
+    assert_eq!(result.is_err(), true);
+    let len: usize = con.xlen("k1").unwrap();
+    assert_eq!(len, 3);
+
+    // producer
+    let producer = IdempotentProducer::new("p3").start_seq(10);
+    let seq = producer.next_seq();
+    assert_eq!(seq, 10);
+    assert_eq!(producer.next_seq(), 11);
+    let first = producer
+        .publish(&mut con, "k2", seq, &[("h", "w")])
+        .unwrap();
+    let again = producer
+        .publish(&mut con, "k2", seq, &[("h", "w")])
+        .unwrap();
+    assert_eq!(first, again);
+    let len: usize = con.xlen("k2").unwrap();
+    assert_eq!(len, 1);
+
+    // the dedup key expires
+    let producer = IdempotentProducer::new("p4").ttl(50);
+    let first = producer.publish(&mut con, "k3", 1, &[("h", "w")]).unwrap();
+    sleep(Duration::from_millis(100));
+    let again = producer.publish(&mut con, "k3", 1, &[("h", "w")]).unwrap();
+    assert_eq!(first != again, true);
+}
+
+#[test]
+fn test_pipeline() {
+    // Tests queueing stream commands in pipelines....
+    // xadd_maxlen
+    // xgroup_create
+    // xread_options
+    // xack
+    // xrange_count
+    // xlen
+    // xack_and_add
+    // xadd_idempotent
+    let ctx = TestContext::new();
+    let mut con = ctx.connection();
+
+    xadd(&mut con);
+
+    let mut pipe = redis::pipe();
+    pipe.xadd_maxlen("k1", StreamMaxlen::Equals(5), AddId::Auto, &[("f", "v")])
+        .xgroup_create("k1", "g1", ReadFrom::After(StreamEntryId::MIN))
+        .ignore()
+        .xread_options(
+            &["k1"],
+            &[GroupReadFrom::Undelivered],
+            StreamReadOptions::default().group("g1", "c1").count(2),
+        )
+        .xack("k1", "g1", &[StreamEntryId::new(1000, 0)])
+        .xrange_count("k1", RangeBound::Min, RangeBound::Max, 2)
+        .xlen("k1");
+
+    let mut replies: StreamPipelineReplies = pipe.query(&mut con).unwrap();
+    assert_eq!(replies.remaining(), 5);
+    let id: StreamEntryId = replies.next_reply().unwrap();
+    let read: StreamReadReply = replies.next_reply().unwrap();
+    assert_eq!(read.keys[0].ids.len(), 2);
+    let acked: usize = replies.next_reply().unwrap();
+    assert_eq!(acked, 1);
+    let range: StreamRangeReply = replies.next_reply().unwrap();
+    assert_eq!(range.ids[0].id, StreamEntryId::new(1000, 0));
+    let len: usize = replies.next_reply().unwrap();
+    assert_eq!(len, 3);
+    assert!(replies.next_reply::<usize>().is_err());
+
+    // atomic pipelines are decoded the same way
+    let mut pipe = redis::pipe();
+    pipe.atomic()
+        .xdel("k1", &[id])
+        .xpending("k1", "g1")
+        .xinfo_stream("k1");
+    let (deleted, pending, info): (usize, StreamPendingReply, StreamInfoStreamReply) =
+        pipe.query(&mut con).unwrap();
+    assert_eq!(deleted, 1);
+    assert_eq!(pending.count(), 1);
+    assert_eq!(info.length, 2);
+
+    // scripts are queued with EVAL
+    let mut pipe = redis::pipe();
+    pipe.xack_and_add("k1", "g1", StreamEntryId::new(1000, 1), "k2", &[("f", "v")])
+        .xadd_idempotent("k2", "p1", 1, 60000, &[("f", "v")])
+        .xadd_idempotent("k2", "p1", 1, 60000, &[("f", "v")]);
+    let (forwarded, first, again): (Option<StreamEntryId>, StreamEntryId, StreamEntryId) =
+        pipe.query(&mut con).unwrap();
+    assert!(forwarded.is_some());
+    assert_eq!(first, again);
+}
+
+#[test]
+fn test_pipeline_replies() {
+    // Tests decoding pipeline results....
+    use redis::{FromRedisValue, Value};
+
+    let value = Value::Bulk(vec![
+        Value::Data(b"1000-0".to_vec()),
+        Value::Int(2),
+        Value::Bulk(vec![]),
+    ]);
+    let mut replies = StreamPipelineReplies::from_redis_value(&value).unwrap();
+    assert_eq!(replies.remaining(), 3);
+    assert_eq!(
+        replies.next_reply::<StreamEntryId>(),
+        Ok(StreamEntryId::new(1000, 0))
+    );
+    assert_eq!(replies.next_reply::<usize>(), Ok(2));
+    let range: StreamRangeReply = replies.next_reply().unwrap();
+    assert_eq!(range.ids.len(), 0);
+    assert_eq!(replies.remaining(), 0);
+    assert!(replies.next_reply::<Value>().is_err());
+}
+
 #[test]
 fn test_xtrim() {
     // Tests the following commands....
@@ -470,12 +2106,35 @@ fn test_xtrim() {
     // we should end up with 40 after this call
     let result: RedisResult<i32> = con.xtrim("k1", StreamMaxlen::Equals(10));
     assert_eq!(result, Ok(40));
+
+    // trim by MINID using explicit ids
+    for i in 1..=10 {
+        let _: RedisResult<String> = con.xadd("k2", StreamEntryId::new(i, 0), &[("h", "w")]);
+    }
+    let trim = StreamTrimStrategy::minid(StreamTrimmingMode::Exact, StreamEntryId::new(4, 0));
+    let result: RedisResult<i32> = con.xtrim("k2", trim);
+    assert_eq!(result, Ok(3));
+
+    // nothing in k2 was added within the last hour
+    let trim = StreamTrimStrategy::minid_ago(StreamTrimmingMode::Exact, Duration::from_secs(3600));
+    let result: RedisResult<i32> = con.xtrim("k2", trim);
+    assert_eq!(result, Ok(7));
+
+    // xadd w/ MINID keeps only recent entries
+    let trim = StreamTrimStrategy::minid_ago(StreamTrimmingMode::Exact, Duration::from_secs(3600));
+    let _: RedisResult<String> = con.xadd("k3", StreamEntryId::new(1, 0), &[("h", "w")]);
+    let _: RedisResult<String> = con.xadd_maxlen("k3", trim, AddId::Auto, &[("h", "w")]);
+    let result: RedisResult<usize> = con.xlen("k3");
+    assert_eq!(result, Ok(1));
 }
 
 #[test]
 fn test_xgroup() {
     // Tests the following commands....
     // xgroup_create_mkstream
+    // xgroup_create_options
+    // xgroup_createconsumer
+    // xgroup_setid_entries_read
     // xgroup_destroy
     // xgroup_delconsumer
 
@@ -483,7 +2142,8 @@ fn test_xgroup() {
     let mut con = ctx.connection();
 
     // test xgroup create w/ mkstream @ 0
-    let result: RedisResult<String> = con.xgroup_create_mkstream("k1", "g1", "0");
+    let result: RedisResult<String> =
+        con.xgroup_create_mkstream("k1", "g1", ReadFrom::After(StreamEntryId::MIN));
     assert_eq!(result.is_ok(), true);
 
     // destroy this new stream group
@@ -494,14 +2154,15 @@ fn test_xgroup() {
     xadd(&mut con);
 
     // create the group again using an existing stream
-    let result: RedisResult<String> = con.xgroup_create("k1", "g1", "0");
+    let result: RedisResult<String> =
+        con.xgroup_create("k1", "g1", ReadFrom::After(StreamEntryId::MIN));
     assert_eq!(result.is_ok(), true);
 
     // read from the group so we can register the consumer
     let reply: StreamReadReply = con
         .xread_options(
             &["k1"],
-            &[">"],
+            &[GroupReadFrom::Undelivered],
             StreamReadOptions::default().group("g1", "c1"),
         )
         .unwrap();
@@ -513,6 +2174,40 @@ fn test_xgroup() {
 
     let result: RedisResult<i32> = con.xgroup_destroy("k1", "g1");
     assert_eq!(result, Ok(1));
+
+    // create the group w/ options, one entry already read
+    let opts = StreamGroupCreateOptions::default()
+        .start(StreamEntryId::new(1000, 0))
+        .entries_read(1);
+    let result: RedisResult<String> = con.xgroup_create_options("k1", "g1", opts);
+    assert_eq!(result.is_ok(), true);
+
+    let reply: StreamInfoGroupsReply = con.xinfo_groups("k1").unwrap();
+    assert_eq!(reply.groups[0].entries_read, Some(1));
+    assert_eq!(reply.groups[0].lag, Some(1));
+
+    // pre-register a consumer
+    let result: RedisResult<i32> = con.xgroup_createconsumer("k1", "g1", "c2");
+    assert_eq!(result, Ok(1));
+    let result: RedisResult<i32> = con.xgroup_createconsumer("k1", "g1", "c2");
+    assert_eq!(result, Ok(0));
+    let reply: StreamInfoConsumersReply = con.xinfo_consumers("k1", "g1").unwrap();
+    assert_eq!(reply.consumers[0].name, "c2");
+    assert_eq!(reply.consumers[0].pending, 0);
+
+    // reset the group to the end of the stream
+    let result: RedisResult<String> = con.xgroup_setid_entries_read("k1", "g1", ReadFrom::New, 2);
+    assert_eq!(result.is_ok(), true);
+    let reply: StreamInfoGroupsReply = con.xinfo_groups("k1").unwrap();
+    assert_eq!(reply.groups[0].entries_read, Some(2));
+    assert_eq!(reply.groups[0].lag, Some(0));
+
+    // mkstream w/ options
+    let opts = StreamGroupCreateOptions::default()
+        .start(StreamEntryId::MIN)
+        .mkstream();
+    let result: RedisResult<String> = con.xgroup_create_options("k5", "g1", opts);
+    assert_eq!(result.is_ok(), true);
 }
 
 #[test]
@@ -531,16 +2226,78 @@ fn test_xrange() {
     let reply: StreamRangeReply = con.xrange_all("k1").unwrap();
     assert_eq!(reply.ids.len(), 2);
 
-    let reply: StreamRangeReply = con.xrange("k1", "1000-1", "+").unwrap();
+    let reply: StreamRangeReply = con
+        .xrange("k1", StreamEntryId::new(1000, 1), RangeBound::Max)
+        .unwrap();
     assert_eq!(reply.ids.len(), 1);
 
-    let reply: StreamRangeReply = con.xrange("k1", "-", "1000-0").unwrap();
+    let reply: StreamRangeReply = con
+        .xrange("k1", RangeBound::Min, StreamEntryId::new(1000, 0))
+        .unwrap();
     assert_eq!(reply.ids.len(), 1);
 
-    let reply: StreamRangeReply = con.xrange_count("k1", "-", "+", 1).unwrap();
+    let reply: StreamRangeReply = con
+        .xrange_count("k1", RangeBound::Min, RangeBound::Max, 1)
+        .unwrap();
     assert_eq!(reply.ids.len(), 1);
 }
 
+#[test]
+fn test_xrange_iter() {
+    // Tests the following commands....
+    // xrange (exclusive bounds)
+    // xrange_iter
+    // xrevrange_iter
+
+    let ctx = TestContext::new();
+    let mut con = ctx.connection();
+
+    for i in 1..=25 {
+        let _: RedisResult<String> = con.xadd("k1", StreamEntryId::new(i, 0), &[("h", "w")]);
+    }
+
+    // exclusive bounds skip the given ids
+    let reply: StreamRangeReply = con
+        .xrange(
+            "k1",
+            RangeBound::Exclusive(StreamEntryId::new(1, 0)),
+            RangeBound::Exclusive(StreamEntryId::new(5, 0)),
+        )
+        .unwrap();
+    assert_eq!(reply.ids.len(), 3);
+
+    // pages of 10 should yield all 25 ids in order
+    let ids: Vec<StreamEntryId> = con
+        .xrange_iter("k1", RangeBound::Min, RangeBound::Max, 10)
+        .map(|r| r.unwrap().id)
+        .collect();
+    assert_eq!(ids.len(), 25);
+    assert_eq!(ids[0], StreamEntryId::new(1, 0));
+    assert_eq!(ids[24], StreamEntryId::new(25, 0));
+
+    // a page size which evenly divides the range
+    let count = con
+        .xrange_iter("k1", StreamEntryId::new(6, 0), RangeBound::Max, 5)
+        .count();
+    assert_eq!(count, 20);
+
+    // reverse iteration
+    let ids: Vec<StreamEntryId> = con
+        .xrevrange_iter("k1", RangeBound::Max, StreamEntryId::new(20, 0), 4)
+        .map(|r| r.unwrap().id)
+        .collect();
+    assert_eq!(ids.len(), 6);
+    assert_eq!(ids[0], StreamEntryId::new(25, 0));
+    assert_eq!(ids[5], StreamEntryId::new(20, 0));
+
+    // missing keys are just empty
+    assert_eq!(
+        con.xrange_iter("k2", RangeBound::Min, RangeBound::Max, 10)
+            .count(),
+        0
+    );
+}
+
 #[test]
 fn test_xrevrange() {
     // Tests the following commands....
@@ -557,12 +2314,18 @@ fn test_xrevrange() {
     let reply: StreamRangeReply = con.xrevrange_all("k1").unwrap();
     assert_eq!(reply.ids.len(), 2);
 
-    let reply: StreamRangeReply = con.xrevrange("k1", "1000-1", "-").unwrap();
+    let reply: StreamRangeReply = con
+        .xrevrange("k1", StreamEntryId::new(1000, 1), RangeBound::Min)
+        .unwrap();
     assert_eq!(reply.ids.len(), 2);
 
-    let reply: StreamRangeReply = con.xrevrange("k1", "+", "1000-1").unwrap();
+    let reply: StreamRangeReply = con
+        .xrevrange("k1", RangeBound::Max, StreamEntryId::new(1000, 1))
+        .unwrap();
     assert_eq!(reply.ids.len(), 1);
 
-    let reply: StreamRangeReply = con.xrevrange_count("k1", "+", "-", 1).unwrap();
+    let reply: StreamRangeReply = con
+        .xrevrange_count("k1", RangeBound::Max, RangeBound::Min, 1)
+        .unwrap();
     assert_eq!(reply.ids.len(), 1);
 }